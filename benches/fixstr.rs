@@ -0,0 +1,138 @@
+//! Compares `FixStr` against `String` and `arrayvec::ArrayString` for the operations
+//! that dominate name-heavy workloads.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use arrayvec::ArrayString;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use fixstr::FixStr;
+
+const NAMES: [&str; 8] = [
+    "id",
+    "player",
+    "position_x",
+    "velocity",
+    "inventory_slot",
+    "health_regen",
+    "team",
+    "last_seen_ms",
+];
+
+fn construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("construction");
+    for name in ["id", "inventory_slot_twelve"] {
+        group.bench_with_input(BenchmarkId::new("FixStr", name), name, |b, s| {
+            b.iter(|| FixStr::<32>::new(black_box(s)));
+        });
+        group.bench_with_input(BenchmarkId::new("ArrayString", name), name, |b, s| {
+            b.iter(|| ArrayString::<32>::from(black_box(s)));
+        });
+        group.bench_with_input(BenchmarkId::new("String", name), name, |b, s| {
+            b.iter(|| String::from(black_box(s)));
+        });
+    }
+    group.finish();
+}
+
+fn comparison(c: &mut Criterion) {
+    let mut group = c.benchmark_group("comparison");
+    let (a, b) = ("inventory_slot_01", "inventory_slot_02");
+
+    let (fa, fb) = (FixStr::<32>::new(a).unwrap(), FixStr::<32>::new(b).unwrap());
+    group.bench_function("FixStr/eq", |bench| {
+        bench.iter(|| black_box(&fa) == black_box(&fb));
+    });
+    group.bench_function("FixStr/cmp", |bench| {
+        bench.iter(|| black_box(&fa).cmp(black_box(&fb)));
+    });
+
+    let (aa, ab) = (
+        ArrayString::<32>::from(a).unwrap(),
+        ArrayString::<32>::from(b).unwrap(),
+    );
+    group.bench_function("ArrayString/eq", |bench| {
+        bench.iter(|| black_box(&aa) == black_box(&ab));
+    });
+    group.bench_function("ArrayString/cmp", |bench| {
+        bench.iter(|| black_box(&aa).cmp(black_box(&ab)));
+    });
+
+    let (sa, sb) = (String::from(a), String::from(b));
+    group.bench_function("String/eq", |bench| {
+        bench.iter(|| black_box(&sa) == black_box(&sb));
+    });
+    group.bench_function("String/cmp", |bench| {
+        bench.iter(|| black_box(&sa).cmp(black_box(&sb)));
+    });
+    group.finish();
+}
+
+fn hashing(c: &mut Criterion) {
+    use std::hash::BuildHasher;
+
+    let mut group = c.benchmark_group("hashing");
+    let state = std::collections::hash_map::RandomState::new();
+    let name = "inventory_slot";
+
+    let fix = FixStr::<32>::new(name).unwrap();
+    group.bench_function("FixStr", |b| b.iter(|| state.hash_one(black_box(&fix))));
+    let array = ArrayString::<32>::from(name).unwrap();
+    group.bench_function("ArrayString", |b| {
+        b.iter(|| state.hash_one(black_box(&array)));
+    });
+    let string = String::from(name);
+    group.bench_function("String", |b| b.iter(|| state.hash_one(black_box(&string))));
+    group.finish();
+}
+
+fn map_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map_lookup");
+
+    let fix: HashMap<FixStr<32>, usize> = NAMES
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (FixStr::new(name).unwrap(), i))
+        .collect();
+    let fix_keys: Vec<FixStr<32>> = NAMES.iter().map(|n| FixStr::new(n).unwrap()).collect();
+    group.bench_function("FixStr", |b| {
+        b.iter(|| fix_keys.iter().map(|k| fix[black_box(k)]).sum::<usize>());
+    });
+
+    let array: HashMap<ArrayString<32>, usize> = NAMES
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (ArrayString::from(name).unwrap(), i))
+        .collect();
+    let array_keys: Vec<ArrayString<32>> = NAMES
+        .iter()
+        .map(|n| ArrayString::from(n).unwrap())
+        .collect();
+    group.bench_function("ArrayString", |b| {
+        b.iter(|| {
+            array_keys
+                .iter()
+                .map(|k| array[black_box(k)])
+                .sum::<usize>()
+        });
+    });
+
+    let string: HashMap<String, usize> = NAMES
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (String::from(*name), i))
+        .collect();
+    let string_keys: Vec<String> = NAMES.iter().map(|n| String::from(*n)).collect();
+    group.bench_function("String", |b| {
+        b.iter(|| {
+            string_keys
+                .iter()
+                .map(|k| string[black_box(k)])
+                .sum::<usize>()
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, construction, comparison, hashing, map_lookup);
+criterion_main!(benches);
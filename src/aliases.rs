@@ -0,0 +1,23 @@
+//! Common capacities, so crates sharing `FixStr` values agree on sizes.
+//!
+//! The numbered aliases name the capacity in octets; the presets name the intended use.
+
+use crate::FixStr;
+
+/// A `FixStr` of 8 octets.
+pub type FixStr8 = FixStr<8>;
+/// A `FixStr` of 16 octets.
+pub type FixStr16 = FixStr<16>;
+/// A `FixStr` of 32 octets.
+pub type FixStr32 = FixStr<32>;
+/// A `FixStr` of 64 octets.
+pub type FixStr64 = FixStr<64>;
+/// A `FixStr` of 255 octets, the largest capacity a `FixStr` can fill.
+pub type FixStr255 = FixStr<255>;
+
+/// A short label such as a category or team tag (16 octets).
+pub type Tag = FixStr16;
+/// A display or player name (32 octets).
+pub type Name = FixStr32;
+/// A short relative path or asset key (64 octets).
+pub type Path64 = FixStr64;
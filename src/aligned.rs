@@ -0,0 +1,126 @@
+use std::fmt;
+use std::ops::Deref;
+
+use crate::FixStr;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Marker types selecting the alignment of an [`AlignedFixStr`].
+///
+/// Implemented by [`Align8`], [`Align16`], [`Align32`] and [`Align64`].
+pub trait Alignment:
+    Copy + Default + PartialEq + Eq + PartialOrd + Ord + std::hash::Hash + sealed::Sealed
+{
+}
+
+macro_rules! alignments {
+    ($($name:ident => $align:literal),*) => {
+        $(
+            #[doc = concat!("Aligns an [`AlignedFixStr`] to ", $align, " octets.")]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[repr(align($align))]
+            pub struct $name;
+
+            impl sealed::Sealed for $name {}
+            impl Alignment for $name {}
+        )*
+    };
+}
+
+alignments!(Align8 => 8, Align16 => 16, Align32 => 32, Align64 => 64);
+
+/// A `FixStr` aligned to `A` (16 octets by default).
+///
+/// Every value starts on an `A` boundary and its size is rounded up to a multiple of it, so
+/// in arrays of keys and in map buckets each string lies on its own aligned block. Hot
+/// probes can then compare keys with aligned vector loads instead of unaligned ones.
+///
+/// ```
+/// use fixstr::{Align32, AlignedFixStr};
+///
+/// let keys: [AlignedFixStr<24, Align32>; 2] =
+///     [AlignedFixStr::new("player").unwrap(), AlignedFixStr::new("team").unwrap()];
+/// assert_eq!(std::mem::align_of_val(&keys[1]), 32);
+/// assert_eq!(std::mem::size_of_val(&keys), 64);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlignedFixStr<const N: usize, A: Alignment = Align16> {
+    s: FixStr<N>,
+    _align: [A; 0],
+}
+
+impl<const N: usize, A: Alignment> AlignedFixStr<N, A> {
+    /// Creates a new `AlignedFixStr` if the input fits within capacity.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        FixStr::new(s).map(Self::from_fixstr)
+    }
+
+    /// Wraps `s`.
+    #[must_use]
+    pub const fn from_fixstr(s: FixStr<N>) -> Self {
+        Self { s, _align: [] }
+    }
+
+    /// Returns the wrapped `FixStr`.
+    #[must_use]
+    pub const fn into_inner(self) -> FixStr<N> {
+        self.s
+    }
+}
+
+impl<const N: usize, A: Alignment> fmt::Debug for AlignedFixStr<N, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AlignedFixStr(\"{}\")", self.s.as_str())
+    }
+}
+
+impl<const N: usize, A: Alignment> fmt::Display for AlignedFixStr<N, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.s, f)
+    }
+}
+
+impl<const N: usize, A: Alignment> Deref for AlignedFixStr<N, A> {
+    type Target = FixStr<N>;
+
+    fn deref(&self) -> &FixStr<N> {
+        &self.s
+    }
+}
+
+impl<const N: usize, A: Alignment> AsRef<str> for AlignedFixStr<N, A> {
+    fn as_ref(&self) -> &str {
+        self.s.as_str()
+    }
+}
+
+impl<const N: usize, A: Alignment> From<FixStr<N>> for AlignedFixStr<N, A> {
+    fn from(s: FixStr<N>) -> Self {
+        Self::from_fixstr(s)
+    }
+}
+
+impl<const N: usize, A: Alignment> From<AlignedFixStr<N, A>> for FixStr<N> {
+    fn from(s: AlignedFixStr<N, A>) -> Self {
+        s.s
+    }
+}
+
+impl<const N: usize, A: Alignment> TryFrom<&str> for AlignedFixStr<N, A> {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        FixStr::try_from(s).map(Self::from_fixstr)
+    }
+}
+
+impl<const N: usize, A: Alignment> TryFrom<String> for AlignedFixStr<N, A> {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
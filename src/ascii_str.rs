@@ -0,0 +1,108 @@
+use std::fmt;
+use std::ops::Deref;
+
+use crate::FixStr;
+
+/// A fixed-capacity string that only holds ASCII.
+///
+/// Every octet is a whole char, so octet offsets, char offsets and case conversions
+/// line up exactly. Read-only `FixStr` methods are available through `Deref`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixAsciiStr<const N: usize>(FixStr<N>);
+
+impl<const N: usize> FixAsciiStr<N> {
+    /// Creates a new `FixAsciiStr` if the input is ASCII and fits within capacity.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        if !s.is_ascii() {
+            return None;
+        }
+        FixStr::new(s).map(Self)
+    }
+
+    /// Returns the octet at `index`, which is also the char at `index`.
+    #[must_use]
+    pub fn byte_at(&self, index: usize) -> Option<u8> {
+        self.0.as_bytes().get(index).copied()
+    }
+
+    /// Returns the underlying `FixStr`.
+    #[must_use]
+    pub const fn as_fixstr(&self) -> &FixStr<N> {
+        &self.0
+    }
+
+    /// Converts all letters to uppercase in place.
+    pub fn make_ascii_uppercase(&mut self) {
+        self.0.make_ascii_uppercase();
+    }
+
+    /// Converts all letters to lowercase in place.
+    pub fn make_ascii_lowercase(&mut self) {
+        self.0.make_ascii_lowercase();
+    }
+
+    /// Returns a copy with all letters converted to uppercase.
+    #[must_use]
+    pub fn to_ascii_uppercase(&self) -> Self {
+        Self(self.0.to_ascii_uppercase())
+    }
+
+    /// Returns a copy with all letters converted to lowercase.
+    #[must_use]
+    pub fn to_ascii_lowercase(&self) -> Self {
+        Self(self.0.to_ascii_lowercase())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixAsciiStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixAsciiStr(\"{}\")", self.0.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for FixAsciiStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> Deref for FixAsciiStr<N> {
+    type Target = FixStr<N>;
+
+    fn deref(&self) -> &FixStr<N> {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsRef<str> for FixAsciiStr<N> {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<const N: usize> From<FixAsciiStr<N>> for FixStr<N> {
+    fn from(s: FixAsciiStr<N>) -> Self {
+        s.0
+    }
+}
+
+impl<const N: usize> TryFrom<FixStr<N>> for FixAsciiStr<N> {
+    type Error = String;
+
+    fn try_from(s: FixStr<N>) -> Result<Self, Self::Error> {
+        if s.as_str().is_ascii() {
+            Ok(Self(s))
+        } else {
+            Err(format!("String '{s}' is not ASCII"))
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for FixAsciiStr<N> {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        FixStr::try_from(s).and_then(Self::try_from)
+    }
+}
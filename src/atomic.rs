@@ -0,0 +1,106 @@
+use std::fmt;
+use std::hint;
+use std::sync::atomic::{fence, AtomicU8, AtomicUsize, Ordering};
+
+use crate::FixStr;
+
+/// A `FixStr` cell that threads can share without a lock, for publishing short status strings
+/// from real-time threads.
+///
+/// Implemented as a sequence lock: a store never blocks on readers, and a load retries until
+/// it has copied a consistent value. Stores from several threads are serialized by spinning,
+/// so the cell is best used with a single writer.
+pub struct AtomicFixStr<const N: usize> {
+    sequence: AtomicUsize,
+    len: AtomicU8,
+    inline: [AtomicU8; N],
+}
+
+impl<const N: usize> AtomicFixStr<N> {
+    /// Creates a new cell holding `s`.
+    #[must_use]
+    pub fn new(s: FixStr<N>) -> Self {
+        let cell = Self::default();
+        cell.store(s);
+        cell
+    }
+
+    /// Replaces the value, making it visible to subsequent loads.
+    pub fn store(&self, s: FixStr<N>) {
+        let mut sequence = self.sequence.load(Ordering::Relaxed);
+        loop {
+            // An odd sequence means another store is in progress
+            if sequence.is_multiple_of(2) {
+                match self.sequence.compare_exchange_weak(
+                    sequence,
+                    sequence.wrapping_add(1),
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => break,
+                    Err(current) => sequence = current,
+                }
+            } else {
+                hint::spin_loop();
+                sequence = self.sequence.load(Ordering::Relaxed);
+            }
+        }
+        fence(Ordering::Release);
+
+        self.len.store(s.len, Ordering::Relaxed);
+        for (target, &b) in self.inline.iter().zip(s.inline.iter()) {
+            target.store(b, Ordering::Relaxed);
+        }
+
+        self.sequence
+            .store(sequence.wrapping_add(2), Ordering::Release);
+    }
+
+    /// Returns a copy of the current value.
+    #[must_use]
+    pub fn load(&self) -> FixStr<N> {
+        loop {
+            let before = self.sequence.load(Ordering::Acquire);
+            if !before.is_multiple_of(2) {
+                hint::spin_loop();
+                continue;
+            }
+
+            let mut inline = [0; N];
+            for (target, b) in inline.iter_mut().zip(self.inline.iter()) {
+                *target = b.load(Ordering::Relaxed);
+            }
+            let len = self.len.load(Ordering::Relaxed) as usize;
+
+            fence(Ordering::Acquire);
+            if self.sequence.load(Ordering::Relaxed) == before {
+                let bytes = inline.get(..len).unwrap_or_default();
+                return crate::utf8::from_utf8(bytes)
+                    .map(FixStr::from_fitting)
+                    .unwrap_or_default();
+            }
+        }
+    }
+}
+
+impl<const N: usize> Default for AtomicFixStr<N> {
+    fn default() -> Self {
+        Self {
+            sequence: AtomicUsize::new(0),
+            len: AtomicU8::new(0),
+            inline: [const { AtomicU8::new(0) }; N],
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for AtomicFixStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AtomicFixStr(\"{}\")", self.load().as_str())
+    }
+}
+
+impl<const N: usize> From<FixStr<N>> for AtomicFixStr<N> {
+    fn from(s: FixStr<N>) -> Self {
+        Self::new(s)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{CapacityError, FixStr};
+
+impl<const N: usize> FixStr<N> {
+    /// Creates a `FixStr` for every string in `strs`, such as a name table loaded from an
+    /// asset.
+    ///
+    /// # Errors
+    /// Returns the index of the first string that does not fit together with its
+    /// `CapacityError`.
+    #[cfg(feature = "alloc")]
+    pub fn try_from_slice_all(strs: &[&str]) -> Result<Vec<Self>, (usize, CapacityError)> {
+        strs.iter()
+            .enumerate()
+            .map(|(i, s)| Self::try_new(s).map_err(|err| (i, err)))
+            .collect()
+    }
+
+    /// Creates a `FixStr` for every string in `strs`, without allocating.
+    ///
+    /// ```
+    /// use fixstr::FixStr;
+    ///
+    /// let [idle, walk] = FixStr::<8>::try_from_array_all(["idle", "walk"]).unwrap();
+    /// assert_eq!(walk.as_str(), "walk");
+    ///
+    /// let (index, _) = FixStr::<4>::try_from_array_all(["idle", "running"]).unwrap_err();
+    /// assert_eq!(index, 1);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the index of the first string that does not fit together with its
+    /// `CapacityError`.
+    pub fn try_from_array_all<const K: usize>(
+        strs: [&str; K],
+    ) -> Result<[Self; K], (usize, CapacityError)> {
+        let mut all = [Self::default(); K];
+        for (i, (slot, s)) in all.iter_mut().zip(strs).enumerate() {
+            *slot = Self::try_new(s).map_err(|err| (i, err))?;
+        }
+        Ok(all)
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(not(feature = "panic-free"))]
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::str::Utf8Error;
+
+use crate::FixStr;
+
+/// A fixed-capacity byte string that is usually, but not necessarily, UTF-8.
+///
+/// Stores up to N octets inline like `FixStr`, for text from foreign systems such as
+/// legacy protocol fields or file names. `Display` replaces invalid sequences with
+/// U+FFFD and `Debug` escapes them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixByteStr<const N: usize> {
+    inline: [u8; N],
+    len: u8,
+}
+
+impl<const N: usize> Default for FixByteStr<N> {
+    fn default() -> Self {
+        Self {
+            inline: [0; N],
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> FixByteStr<N> {
+    /// Creates a new `FixByteStr` if the input fits within capacity.
+    ///
+    /// Returns `None` if the input is too long (> N octets) or exceeds `u8::MAX`.
+    #[must_use]
+    pub fn new(bytes: &[u8]) -> Option<Self> {
+        let len = u8::try_from(bytes.len()).ok()?;
+        let mut inline = [0; N];
+        inline.get_mut(..bytes.len())?.copy_from_slice(bytes);
+        Some(Self { inline, len })
+    }
+
+    /// Returns the used octets.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.inline.get(..self.len as usize).unwrap_or_default()
+    }
+
+    /// Returns the contents as a `str` if they are valid UTF-8.
+    ///
+    /// # Errors
+    /// Returns the `Utf8Error` describing the first invalid sequence.
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        crate::utf8::from_utf8(self.as_bytes())
+    }
+
+    /// Returns the contents as text, replacing invalid sequences with U+FFFD.
+    ///
+    /// Not available with the `panic-free` feature, as the replacement allocates.
+    #[cfg(not(feature = "panic-free"))]
+    #[must_use]
+    pub fn to_str_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(self.as_bytes())
+    }
+
+    /// Returns the length in octets.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if the byte string is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total capacity in octets.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixByteStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("FixByteStr(\"")?;
+        for chunk in self.as_bytes().utf8_chunks() {
+            write!(f, "{}", chunk.valid().escape_debug())?;
+            for b in chunk.invalid() {
+                write!(f, "\\x{b:02X}")?;
+            }
+        }
+        f.write_str("\")")
+    }
+}
+
+impl<const N: usize> fmt::Display for FixByteStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.as_bytes().utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                f.write_char(char::REPLACEMENT_CHARACTER)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixByteStr<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> From<FixStr<N>> for FixByteStr<N> {
+    fn from(s: FixStr<N>) -> Self {
+        // Copies only the used octets; the tail may hold uncommitted spare capacity
+        Self::new(s.as_bytes()).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> TryFrom<FixByteStr<N>> for FixStr<N> {
+    type Error = Utf8Error;
+
+    fn try_from(s: FixByteStr<N>) -> Result<Self, Self::Error> {
+        s.to_str().map(Self::from_fitting)
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for FixByteStr<N> {
+    type Error = String;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::new(bytes).ok_or(format!("Bytes (len={}) exceed capacity {N}", bytes.len()))
+    }
+}
@@ -0,0 +1,98 @@
+use std::ffi::{c_char, CStr};
+use std::fmt;
+
+use crate::FixStr;
+
+/// A fixed-capacity, NUL-terminated string for handing text to C APIs.
+///
+/// The terminating NUL is kept inside the `N`-octet buffer, so at most `N - 1` octets of
+/// text fit, and the text itself never contains NUL.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixCString<const N: usize>(FixStr<N>);
+
+impl<const N: usize> FixCString<N> {
+    /// Creates a new `FixCString` if the input has no interior NUL and leaves room for
+    /// the terminating NUL.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        if s.len() >= N || s.contains('\0') {
+            return None;
+        }
+        FixStr::new(s).map(Self)
+    }
+
+    /// Returns the string as a `CStr`, including the terminating NUL.
+    #[must_use]
+    pub fn as_c_str(&self) -> &CStr {
+        // The buffer only ever comes from `FixStr::new`, which zeroes the octets past `len`,
+        // and is never handed out mutably, so `new` leaves at least one NUL after the text
+        CStr::from_bytes_until_nul(&self.0.inline).unwrap_or_default()
+    }
+
+    /// Returns a pointer to the NUL-terminated string, valid for as long as `self` is.
+    #[must_use]
+    pub fn as_ptr(&self) -> *const c_char {
+        self.as_c_str().as_ptr()
+    }
+
+    /// Returns the text without the terminating NUL.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the length of the text in octets, not counting the terminating NUL.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the text is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the maximum length of the text in octets, which is `N - 1`.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        N.saturating_sub(1)
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixCString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixCString(\"{}\")", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for FixCString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<const N: usize> AsRef<CStr> for FixCString<N> {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl<const N: usize> From<FixCString<N>> for FixStr<N> {
+    fn from(s: FixCString<N>) -> Self {
+        s.0
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for FixCString<N> {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s).ok_or(format!(
+            "String '{}' (len={}) contains NUL or exceeds capacity {}",
+            s.escape_debug(),
+            s.len(),
+            N.saturating_sub(1)
+        ))
+    }
+}
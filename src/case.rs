@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+
+use crate::{CapacityError, FixStr};
+
+impl<const N: usize> FixStr<N> {
+    /// Converts ASCII letters to uppercase in place, leaving other bytes unchanged.
+    pub fn make_ascii_uppercase(&mut self) {
+        let len = self.len();
+        if let Some(bytes) = self.inline.get_mut(..len) {
+            bytes.make_ascii_uppercase();
+        }
+    }
+
+    /// Converts ASCII letters to lowercase in place, leaving other bytes unchanged.
+    pub fn make_ascii_lowercase(&mut self) {
+        let len = self.len();
+        if let Some(bytes) = self.inline.get_mut(..len) {
+            bytes.make_ascii_lowercase();
+        }
+    }
+
+    /// Returns a copy with ASCII letters converted to uppercase.
+    #[must_use]
+    pub fn to_ascii_uppercase(&self) -> Self {
+        let mut upper = *self;
+        upper.make_ascii_uppercase();
+        upper
+    }
+
+    /// Returns a copy with ASCII letters converted to lowercase.
+    #[must_use]
+    pub fn to_ascii_lowercase(&self) -> Self {
+        let mut lower = *self;
+        lower.make_ascii_lowercase();
+        lower
+    }
+
+    /// Returns the Unicode uppercase equivalent of the string.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the mapping grows the string beyond capacity (e.g. `ŉ` → `ʼN`).
+    pub fn try_to_uppercase(&self) -> Result<Self, CapacityError> {
+        Self::try_from_chars(self.as_str().chars().flat_map(char::to_uppercase))
+    }
+
+    /// Returns the Unicode lowercase equivalent of the string.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the mapping grows the string beyond capacity (e.g. `Ⱥ` → `ⱥ`).
+    pub fn try_to_lowercase(&self) -> Result<Self, CapacityError> {
+        Self::try_from_chars(self.as_str().chars().flat_map(char::to_lowercase))
+    }
+
+    /// Returns a copy with the first char converted to ASCII uppercase.
+    #[must_use]
+    pub fn capitalize(&self) -> Self {
+        let mut capitalized = *self;
+        let len = self.len();
+        if let Some(first) = capitalized
+            .inline
+            .get_mut(..len)
+            .and_then(<[u8]>::first_mut)
+        {
+            first.make_ascii_uppercase();
+        }
+        capitalized
+    }
+
+    /// Returns a copy where each word starts with an ASCII uppercase letter and continues in
+    /// lowercase. Words are separated by any ASCII char that is not alphanumeric, so
+    /// `"player_one-ready"` becomes `"Player_One-Ready"`; non-ASCII letters stay part of
+    /// their word.
+    #[must_use]
+    pub fn to_title_case_ascii(&self) -> Self {
+        let mut titled = *self;
+        let len = self.len();
+        let mut word_start = true;
+        for b in titled.inline.iter_mut().take(len) {
+            if word_start {
+                b.make_ascii_uppercase();
+            } else {
+                b.make_ascii_lowercase();
+            }
+            word_start = b.is_ascii() && !b.is_ascii_alphanumeric();
+        }
+        titled
+    }
+
+    /// Checks that the string equals `other`, ignoring ASCII case.
+    #[must_use]
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// Compares the string with `other` as if both were ASCII lowercase.
+    #[must_use]
+    pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+        let lower = |b: &u8| b.to_ascii_lowercase();
+        self.as_bytes()
+            .iter()
+            .map(lower)
+            .cmp(other.as_bytes().iter().map(lower))
+    }
+
+    /// Checks that the string equals `other` under full Unicode case folding,
+    /// so that e.g. `"STRASSE"` matches `"straße"`.
+    #[cfg(feature = "case-fold")]
+    #[must_use]
+    pub fn eq_fold(&self, other: &str) -> bool {
+        use ::caseless::Caseless;
+
+        self.as_str()
+            .chars()
+            .default_case_fold()
+            .eq(other.chars().default_case_fold())
+    }
+}
@@ -0,0 +1,88 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::FixStr;
+
+/// A `FixStr` whose equality, ordering and hashing ignore ASCII case.
+///
+/// The original spelling is kept, so `CaselessFixStr` works as a map key for
+/// user-entered identifiers while still displaying them as typed.
+#[derive(Clone, Copy, Default)]
+pub struct CaselessFixStr<const N: usize>(pub FixStr<N>);
+
+impl<const N: usize> CaselessFixStr<N> {
+    /// Returns the wrapped `FixStr`.
+    #[must_use]
+    pub const fn into_inner(self) -> FixStr<N> {
+        self.0
+    }
+}
+
+impl<const N: usize> fmt::Debug for CaselessFixStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CaselessFixStr(\"{}\")", self.0.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for CaselessFixStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> PartialEq for CaselessFixStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(other.0.as_str())
+    }
+}
+
+impl<const N: usize> Eq for CaselessFixStr<N> {}
+
+impl<const N: usize> Ord for CaselessFixStr<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_ignore_ascii_case(other.0.as_str())
+    }
+}
+
+impl<const N: usize> PartialOrd for CaselessFixStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hashes the ASCII-lowercased octets, consistent with `PartialEq`.
+impl<const N: usize> Hash for CaselessFixStr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_ascii_lowercase().hash(state);
+    }
+}
+
+impl<const N: usize> Deref for CaselessFixStr<N> {
+    type Target = FixStr<N>;
+
+    fn deref(&self) -> &FixStr<N> {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<FixStr<N>> for CaselessFixStr<N> {
+    fn from(s: FixStr<N>) -> Self {
+        Self(s)
+    }
+}
+
+impl<const N: usize> From<CaselessFixStr<N>> for FixStr<N> {
+    fn from(s: CaselessFixStr<N>) -> Self {
+        s.0
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for CaselessFixStr<N> {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        FixStr::try_from(s).map(Self)
+    }
+}
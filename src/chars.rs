@@ -0,0 +1,86 @@
+use std::str::{Bytes, CharIndices, Chars};
+
+use crate::FixStr;
+
+impl<const N: usize> FixStr<N> {
+    /// Returns an iterator over the chars.
+    pub fn chars(&self) -> Chars<'_> {
+        self.as_str().chars()
+    }
+
+    /// Returns an iterator over the used octets.
+    pub fn bytes(&self) -> Bytes<'_> {
+        self.as_str().bytes()
+    }
+
+    /// Returns the char starting at octet offset `index`, or `None` if `index` is out of
+    /// bounds or not on a char boundary.
+    #[must_use]
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        self.as_str().get(index..)?.chars().next()
+    }
+
+    /// Returns the `n`-th char (counting chars, not octets).
+    #[must_use]
+    pub fn nth_char(&self, n: usize) -> Option<char> {
+        self.as_str().chars().nth(n)
+    }
+
+    /// Returns an iterator over the chars and their octet offsets.
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        self.as_str().char_indices()
+    }
+
+    /// Checks whether `index` is the start of a char or the end of the string.
+    #[must_use]
+    pub fn is_char_boundary(&self, index: usize) -> bool {
+        self.as_str().is_char_boundary(index)
+    }
+
+    /// Returns the closest char boundary at or before `index`, clamped to the length.
+    #[must_use]
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        let mut index = index.min(self.len());
+        while !self.is_char_boundary(index) {
+            index -= 1;
+        }
+        index
+    }
+
+    /// Returns the closest char boundary at or after `index`, clamped to the length.
+    #[must_use]
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        let mut index = index.min(self.len());
+        while !self.is_char_boundary(index) {
+            index += 1;
+        }
+        index
+    }
+
+    /// Returns true if `pred` holds for every char, including for an empty string.
+    #[must_use]
+    pub fn all_chars(&self, pred: impl FnMut(char) -> bool) -> bool {
+        self.chars().all(pred)
+    }
+
+    /// Returns true if the string is not empty and every char is alphabetic or numeric.
+    #[must_use]
+    pub fn is_alphanumeric(&self) -> bool {
+        !self.is_empty() && self.all_chars(char::is_alphanumeric)
+    }
+
+    /// Returns true if the string is not empty and every char is numeric.
+    ///
+    /// Like `char::is_numeric` this accepts any Unicode number such as `¾` or `٣`; use
+    /// `all_chars(|c| c.is_ascii_digit())` to only accept `0` to `9`.
+    #[must_use]
+    pub fn is_numeric(&self) -> bool {
+        !self.is_empty() && self.all_chars(char::is_numeric)
+    }
+
+    /// Returns true if the string is not empty and every char is alphabetic.
+    #[must_use]
+    pub fn is_alphabetic(&self) -> bool {
+        !self.is_empty() && self.all_chars(char::is_alphabetic)
+    }
+}
@@ -0,0 +1,27 @@
+use std::iter;
+
+use ::unicode_normalization::char::is_combining_mark;
+use ::unicode_normalization::UnicodeNormalization;
+
+use crate::{CapacityError, FixStr};
+
+impl<const N: usize> FixStr<N> {
+    /// Returns a sort key whose byte order matches a human-sensible, locale-independent order.
+    ///
+    /// Strings are ordered by their letters with accents and case removed first, so `"Émile"`
+    /// sorts between `"adam"` and `"eve"`, and only then by their exact spelling, so different
+    /// strings never get equal keys. The key is itself
+    /// a `FixStr`, so it can be compared directly or stored next to the name it was built from.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the key does not fit in `M` octets; `2 * N + 1` is always enough
+    /// for strings without decomposing characters.
+    pub fn collation_key<const M: usize>(&self) -> Result<FixStr<M>, CapacityError> {
+        let primary = self
+            .as_str()
+            .nfd()
+            .filter(|&c| !is_combining_mark(c))
+            .flat_map(char::to_lowercase);
+        FixStr::try_from_chars(primary.chain(iter::once('\0')).chain(self.as_str().chars()))
+    }
+}
@@ -0,0 +1,58 @@
+use std::cmp::Ordering;
+
+use crate::FixStr;
+
+impl<const N: usize> FixStr<N> {
+    /// Returns true if both strings are equal, usable in const contexts.
+    ///
+    /// ```
+    /// use fixstr::{fixconcat, FixStr};
+    ///
+    /// const COMMANDS: [FixStr<4>; 3] = [fixconcat!("help"), fixconcat!("quit"), fixconcat!("load")];
+    ///
+    /// const fn all_unique<const N: usize>(names: &[FixStr<N>]) -> bool {
+    ///     let mut i = 0;
+    ///     while i < names.len() {
+    ///         let mut j = i + 1;
+    ///         while j < names.len() {
+    ///             if names[i].const_eq(&names[j]) {
+    ///                 return false;
+    ///             }
+    ///             j += 1;
+    ///         }
+    ///         i += 1;
+    ///     }
+    ///     true
+    /// }
+    ///
+    /// const _: () = assert!(all_unique(&COMMANDS));
+    /// ```
+    #[must_use]
+    pub const fn const_eq(&self, other: &FixStr<N>) -> bool {
+        matches!(self.const_cmp(other), Ordering::Equal)
+    }
+
+    /// Compares both strings the same way as `Ord`, usable in const contexts.
+    #[must_use]
+    pub const fn const_cmp(&self, other: &FixStr<N>) -> Ordering {
+        let (a, b) = (self.as_bytes(), other.as_bytes());
+        let mut i = 0;
+        while i < a.len() && i < b.len() {
+            if a[i] != b[i] {
+                return if a[i] < b[i] {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+            i += 1;
+        }
+        if a.len() < b.len() {
+            Ordering::Less
+        } else if a.len() > b.len() {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use crate::FixStr;
+
+/// Concatenates string literals and const `FixStr` values at compile time into a `FixStr`
+/// whose capacity is exactly the combined length.
+///
+/// ```
+/// use fixstr::{fixconcat, FixStr};
+///
+/// const PREFIX: FixStr<4> = fixconcat!("app/");
+/// const HELLO: FixStr<9> = fixconcat!(PREFIX, "hello");
+/// assert_eq!(HELLO.as_str(), "app/hello");
+/// ```
+///
+/// Results longer than 255 octets, or parts whose `as_bytes()` is not valid UTF-8, are
+/// rejected at compile time:
+///
+/// ```compile_fail
+/// struct Raw;
+///
+/// impl Raw {
+///     const fn as_bytes(&self) -> &'static [u8] {
+///         &[0xFF, 0xFE]
+///     }
+/// }
+///
+/// let invalid = fixstr::fixconcat!("ok", Raw);
+/// ```
+#[macro_export]
+macro_rules! fixconcat {
+    ($($part:expr),+ $(,)?) => {{
+        const PARTS: &[&[u8]] = &[$($part.as_bytes()),+];
+        const RESULT: $crate::FixStr<{ $crate::__private::concat_len(PARTS) }> =
+            $crate::__private::concat(PARTS);
+        RESULT
+    }};
+}
+
+#[doc(hidden)]
+#[must_use]
+pub const fn concat_len(parts: &[&[u8]]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Only called by `fixconcat!`, where `N` is the exact length of the parts.
+#[doc(hidden)]
+#[must_use]
+pub const fn concat<const N: usize>(parts: &[&[u8]]) -> FixStr<N> {
+    assert!(
+        concat_len(parts) == N && N <= u8::MAX as usize,
+        "fixconcat! result exceeds 255 octets"
+    );
+    let mut inline = [0; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let mut j = 0;
+        while j < parts[i].len() {
+            inline[len] = parts[i][j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(
+        std::str::from_utf8(&inline).is_ok(),
+        "fixconcat! parts must be valid UTF-8"
+    );
+    FixStr {
+        inline,
+        len: N as u8,
+        _marker: PhantomData,
+    }
+}
@@ -0,0 +1,25 @@
+use std::hint::black_box;
+
+use crate::FixStr;
+
+impl<const N: usize> FixStr<N> {
+    /// Compares in constant time, for secrets such as tokens or PINs.
+    ///
+    /// Unlike `==` it visits all `N` octets of both buffers and never stops at the first
+    /// difference, so the time taken reveals neither the matching prefix nor the lengths.
+    #[must_use]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        black_box(self.ct_diff(other)) == 0
+    }
+
+    /// Returns zero if the strings are equal, without data-dependent branches.
+    pub(crate) fn ct_diff(&self, other: &Self) -> u8 {
+        // Octets past the length are masked out rather than relied on to be zero
+        let mask = |i: usize, len: u8| 0u8.wrapping_sub(u8::from(i < usize::from(len)));
+        let mut diff = self.len ^ other.len;
+        for (i, (a, b)) in self.inline.iter().zip(other.inline.iter()).enumerate() {
+            diff |= (a & mask(i, self.len)) ^ (b & mask(i, other.len));
+        }
+        diff
+    }
+}
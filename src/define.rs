@@ -0,0 +1,287 @@
+use std::fmt;
+
+use crate::{CapacityError, FixStr};
+
+/// Error returned by the constructors of types generated with [`define_fixstr!`](crate::define_fixstr).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The string does not fit in the capacity of the type.
+    Capacity(CapacityError),
+    /// The string fits but was rejected by the validator of the named type.
+    Invalid {
+        /// Name of the type whose validator rejected the string.
+        type_name: &'static str,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(err) => fmt::Display::fmt(err, f),
+            Self::Invalid { type_name } => write!(f, "String is not a valid {type_name}"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Capacity(err) => Some(err),
+            Self::Invalid { .. } => None,
+        }
+    }
+}
+
+/// Defines a newtype over `FixStr<N>` that only holds strings accepted by a validator.
+///
+/// The validator is a `fn(&str) -> bool` or non-capturing closure. The generated type gets
+/// `new`, `as_str`, `as_fixstr`, `into_inner`, `Deref<Target = FixStr<N>>`, `Debug`, `Display`,
+/// `FromStr`, `TryFrom<&str>` and, with the `serde` feature, `Serialize`/`Deserialize`, all
+/// reporting failures as [`ValidationError`].
+///
+/// ```
+/// fixstr::define_fixstr!(
+///     /// A player name of letters, digits and underscores.
+///     pub PlayerName, 24, |s| !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+/// );
+///
+/// let name: PlayerName = "dark_knight".parse().unwrap();
+/// assert_eq!(name.as_str(), "dark_knight");
+/// assert!(PlayerName::new("no spaces").is_err());
+/// ```
+///
+/// Prefixing the validator with `const` takes the path of a `const fn(&str) -> bool` instead.
+/// `new` then becomes a `const fn` returning `Option<Self>`, like `FixStr::new`, and the
+/// `ValidationError` constructor is `try_new`. The name may also take a const capacity
+/// parameter:
+///
+/// ```
+/// const fn is_upper(s: &str) -> bool {
+///     let mut i = 0;
+///     while i < s.len() {
+///         if !s.as_bytes()[i].is_ascii_uppercase() {
+///             return false;
+///         }
+///         i += 1;
+///     }
+///     true
+/// }
+///
+/// fixstr::define_fixstr!(pub Shout<N>, N, const is_upper);
+///
+/// const HEY: Shout<4> = Shout::new("HEY").unwrap();
+/// assert_eq!(HEY.as_str(), "HEY");
+/// assert!(Shout::<4>::try_new("hey").is_err());
+/// ```
+///
+/// The generated type derives `PartialOrd` and `Ord` from the string. Appending `manual_ord`
+/// after the validator leaves both to be implemented by hand, for types with another order.
+#[macro_export]
+macro_rules! define_fixstr {
+    (
+        $(#[$meta:meta])* $vis:vis $name:ident $(<$n:ident>)?, $capacity:expr,
+        const $validator:path $(, $ord:ident)? $(,)?
+    ) => {
+        $crate::__define_fixstr_struct!(
+            [$($ord)?] $(#[$meta])* $vis $name $(<$n>)?, $capacity
+        );
+
+        impl$(<const $n: usize>)? $name$(<$n>)? {
+            /// Creates a new value if `s` fits within capacity and passes validation.
+            ///
+            /// Usable in const context.
+            #[must_use]
+            pub const fn new(s: &str) -> ::core::option::Option<Self> {
+                if !$validator(s) {
+                    return None;
+                }
+                match $crate::__private::new_const(s) {
+                    Some(fixed) => Some(Self(fixed)),
+                    None => None,
+                }
+            }
+
+            /// Creates a new value if `s` fits within capacity and passes validation.
+            ///
+            /// # Errors
+            /// Returns `ValidationError` describing why `s` was rejected.
+            pub fn try_new(s: &str) -> ::core::result::Result<Self, $crate::ValidationError> {
+                let fixed =
+                    $crate::FixStr::try_new(s).map_err($crate::ValidationError::Capacity)?;
+                if $validator(s) {
+                    Ok(Self(fixed))
+                } else {
+                    Err($crate::ValidationError::Invalid {
+                        type_name: stringify!($name),
+                    })
+                }
+            }
+        }
+
+        $crate::__define_fixstr_impls!($name $(<$n>)?, $capacity, try_new);
+    };
+    (
+        $(#[$meta:meta])* $vis:vis $name:ident $(<$n:ident>)?, $capacity:expr,
+        $validator:expr $(, $ord:ident)? $(,)?
+    ) => {
+        $crate::__define_fixstr_struct!(
+            [$($ord)?] $(#[$meta])* $vis $name $(<$n>)?, $capacity
+        );
+
+        impl$(<const $n: usize>)? $name$(<$n>)? {
+            /// Creates a new value if `s` fits within capacity and passes validation.
+            ///
+            /// # Errors
+            /// Returns `ValidationError` describing why `s` was rejected.
+            pub fn new(s: &str) -> ::core::result::Result<Self, $crate::ValidationError> {
+                let validate: fn(&str) -> bool = $validator;
+                let fixed =
+                    $crate::FixStr::try_new(s).map_err($crate::ValidationError::Capacity)?;
+                if validate(s) {
+                    Ok(Self(fixed))
+                } else {
+                    Err($crate::ValidationError::Invalid {
+                        type_name: stringify!($name),
+                    })
+                }
+            }
+        }
+
+        $crate::__define_fixstr_impls!($name $(<$n>)?, $capacity, new);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_fixstr_struct {
+    ([] $(#[$meta:meta])* $vis:vis $name:ident $(<$n:ident>)?, $capacity:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name$(<const $n: usize>)?($crate::FixStr<{ $capacity }>);
+    };
+    ([manual_ord] $(#[$meta:meta])* $vis:vis $name:ident $(<$n:ident>)?, $capacity:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $name$(<const $n: usize>)?($crate::FixStr<{ $capacity }>);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_fixstr_impls {
+    ($name:ident $(<$n:ident>)?, $capacity:expr, $try_new:ident) => {
+        impl$(<const $n: usize>)? $name$(<$n>)? {
+            /// Returns a string slice containing the entire string.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                self.0.as_str()
+            }
+
+            /// Returns the underlying `FixStr`.
+            #[must_use]
+            pub const fn as_fixstr(&self) -> &$crate::FixStr<{ $capacity }> {
+                &self.0
+            }
+
+            /// Returns the wrapped `FixStr`.
+            #[must_use]
+            pub const fn into_inner(self) -> $crate::FixStr<{ $capacity }> {
+                self.0
+            }
+        }
+
+        impl$(<const $n: usize>)? ::core::fmt::Debug for $name$(<$n>)? {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{}(\"{}\")", stringify!($name), self.0.as_str())
+            }
+        }
+
+        impl$(<const $n: usize>)? ::core::fmt::Display for $name$(<$n>)? {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl$(<const $n: usize>)? ::core::ops::Deref for $name$(<$n>)? {
+            type Target = $crate::FixStr<{ $capacity }>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl$(<const $n: usize>)? ::core::convert::AsRef<str> for $name$(<$n>)? {
+            fn as_ref(&self) -> &str {
+                self.0.as_str()
+            }
+        }
+
+        impl$(<const $n: usize>)? ::core::str::FromStr for $name$(<$n>)? {
+            type Err = $crate::ValidationError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::$try_new(s)
+            }
+        }
+
+        impl$(<const $n: usize>)? ::core::convert::TryFrom<&str> for $name$(<$n>)? {
+            type Error = $crate::ValidationError;
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                Self::$try_new(s)
+            }
+        }
+
+        impl$(<const $n: usize>)? ::core::convert::From<$name$(<$n>)?>
+            for $crate::FixStr<{ $capacity }>
+        {
+            fn from(value: $name$(<$n>)?) -> Self {
+                value.0
+            }
+        }
+
+        $crate::__define_fixstr_serde!($name $(<$n>)?, $capacity, $try_new);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_fixstr_serde {
+    ($name:ident $(<$n:ident>)?, $capacity:expr, $try_new:ident) => {
+        impl$(<const $n: usize>)? $crate::__private::serde::Serialize for $name$(<$n>)? {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de, $(const $n: usize)?> $crate::__private::serde::Deserialize<'de>
+            for $name$(<$n>)?
+        {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let s: $crate::FixStr<{ $capacity }> =
+                    $crate::__private::serde::Deserialize::deserialize(deserializer)?;
+                Self::$try_new(s.as_str()).map_err($crate::__private::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_fixstr_serde {
+    ($name:ident $(<$n:ident>)?, $capacity:expr, $try_new:ident) => {};
+}
+
+#[doc(hidden)]
+#[must_use]
+pub const fn new_const<const N: usize>(s: &str) -> Option<FixStr<N>> {
+    FixStr::new_const(s)
+}
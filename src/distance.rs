@@ -0,0 +1,43 @@
+use crate::FixStr;
+
+impl<const N: usize> FixStr<N> {
+    /// Returns the Levenshtein distance to `other`, counted in chars.
+    ///
+    /// Computed without allocating, since this string holds at most 255 chars.
+    #[must_use]
+    pub fn edit_distance(&self, other: &str) -> usize {
+        let mut row = [0usize; u8::MAX as usize + 1];
+        let width = self.char_len();
+        for (i, cell) in row.iter_mut().enumerate().take(width + 1) {
+            *cell = i;
+        }
+
+        for (j, other_char) in other.chars().enumerate() {
+            let Some((first, rest)) = row.split_first_mut() else {
+                break;
+            };
+            // `diagonal` holds the previous row's value left of `cell`, `left` the new one
+            let mut diagonal = *first;
+            *first = j + 1;
+            let mut left = *first;
+            for (cell, self_char) in rest.iter_mut().zip(self.as_str().chars()) {
+                let substitution = diagonal + usize::from(self_char != other_char);
+                diagonal = *cell;
+                *cell = substitution.min(left + 1).min(diagonal + 1);
+                left = *cell;
+            }
+        }
+        row.get(width).copied().unwrap_or_default()
+    }
+
+    /// Returns how similar the string is to `other`, from `0.0` (nothing in common) to
+    /// `1.0` (equal), based on `edit_distance`.
+    #[must_use]
+    pub fn similarity(&self, other: &str) -> f64 {
+        let longest = self.char_len().max(other.chars().count());
+        if longest == 0 {
+            return 1.0;
+        }
+        1.0 - self.edit_distance(other) as f64 / longest as f64
+    }
+}
@@ -0,0 +1,24 @@
+fn is_dns_label(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    matches!(bytes.len(), 1..=63)
+        && bytes.first() != Some(&b'-')
+        && bytes.last() != Some(&b'-')
+        && bytes
+            .iter()
+            .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+}
+
+fn is_hostname(s: &str) -> bool {
+    s.split('.').all(is_dns_label)
+}
+
+crate::define_fixstr!(
+    /// A single DNS label following RFC 1123: 1 to 63 ASCII letters, digits and hyphens,
+    /// neither starting nor ending with a hyphen.
+    pub DnsLabel, 63, is_dns_label
+);
+
+crate::define_fixstr!(
+    /// A hostname of at most 253 octets made of dot-separated [`DnsLabel`]s.
+    pub Hostname, 253, is_hostname
+);
@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::FixStr;
+
+/// A string stored inline in a `FixStr<N>` when it fits, and on the heap otherwise.
+///
+/// Construction never fails, and strings of up to `N` octets (or 255, whichever is smaller)
+/// never allocate.
+#[derive(Clone)]
+pub enum FlexStr<const N: usize> {
+    /// A string that fits inline.
+    Inline(FixStr<N>),
+    /// A string too long to fit inline.
+    Heap(Box<str>),
+}
+
+impl<const N: usize> FlexStr<N> {
+    /// Creates a new `FlexStr`, only allocating if `s` does not fit inline.
+    #[must_use]
+    pub fn new(s: &str) -> Self {
+        FixStr::new(s).map_or_else(|| Self::Heap(Box::from(s)), Self::Inline)
+    }
+
+    /// Returns a string slice containing the entire string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Inline(s) => s.as_str(),
+            Self::Heap(s) => s,
+        }
+    }
+
+    /// Returns the length of the string in octets.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns true if the string is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the string is stored inline.
+    #[must_use]
+    pub fn is_inline(&self) -> bool {
+        matches!(self, Self::Inline(_))
+    }
+}
+
+impl<const N: usize> Default for FlexStr<N> {
+    fn default() -> Self {
+        Self::Inline(FixStr::default())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FlexStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FlexStr(\"{}\")", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for FlexStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> PartialEq for FlexStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for FlexStr<N> {}
+
+impl<const N: usize> Ord for FlexStr<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> PartialOrd for FlexStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Hash for FlexStr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> AsRef<str> for FlexStr<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> From<&str> for FlexStr<N> {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize> From<String> for FlexStr<N> {
+    fn from(s: String) -> Self {
+        FixStr::new(&s).map_or_else(|| Self::Heap(s.into_boxed_str()), Self::Inline)
+    }
+}
+
+impl<const N: usize> From<FixStr<N>> for FlexStr<N> {
+    fn from(s: FixStr<N>) -> Self {
+        Self::Inline(s)
+    }
+}
+
+impl<const N: usize> From<FlexStr<N>> for String {
+    fn from(s: FlexStr<N>) -> Self {
+        match s {
+            FlexStr::Inline(s) => s.into(),
+            FlexStr::Heap(s) => s.into_string(),
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use crate::{CapacityError, FixStr};
+
+/// Collects formatted output into a `FixStr`, measuring the full length even once it
+/// no longer fits.
+struct FixStrWriter<const N: usize> {
+    s: FixStr<N>,
+    required: usize,
+    overflowed: bool,
+}
+
+impl<const N: usize> FixStrWriter<N> {
+    fn new() -> Self {
+        Self {
+            s: FixStr::default(),
+            required: 0,
+            overflowed: false,
+        }
+    }
+
+    fn finish(self) -> Result<FixStr<N>, CapacityError> {
+        if self.overflowed {
+            return Err(CapacityError {
+                len: self.required,
+                capacity: N,
+            });
+        }
+        Ok(self.s)
+    }
+
+    /// Writes `value` in decimal, zero-padded to at least `width` digits, without going
+    /// through `fmt`, which the optimizer cannot see into for the `panic-free` guarantee.
+    fn write_decimal(&mut self, value: u128, width: usize) {
+        // u128::MAX has 39 digits
+        let mut digits = [0u8; 39];
+        let mut start = digits.len();
+        let mut rest = value;
+        loop {
+            start -= 1;
+            if let Some(digit) = digits.get_mut(start) {
+                *digit = b'0' + (rest % 10) as u8;
+            }
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        let digits = digits.get(start..).unwrap_or_default();
+        let zeros = width.saturating_sub(digits.len());
+        if zeros > N {
+            // Far too wide to fit, so only measure instead of writing zeros one by one
+            self.required = self.required.saturating_add(zeros + digits.len());
+            self.overflowed = true;
+            return;
+        }
+        for _ in 0..zeros {
+            let _ = self.write_char('0');
+        }
+        for &digit in digits {
+            let _ = self.write_char(char::from(digit));
+        }
+    }
+}
+
+impl<const N: usize> Write for FixStrWriter<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.required = self.required.saturating_add(s.len());
+        if !self.overflowed {
+            self.overflowed = self.s.try_push_str(s).is_err();
+        }
+        Ok(())
+    }
+}
+
+/// How [`FixStr::from_duration`] renders a duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DurationStyle {
+    /// Non-zero hours, minutes and seconds with unit suffixes, such as `"1h 5s"` or `"3m 12s"`.
+    /// Durations below a second are shown in milliseconds, such as `"250ms"`.
+    #[default]
+    Compact,
+    /// Hours, minutes and seconds as a clock, such as `"00:03:12"`.
+    Clock,
+}
+
+/// Error returned by [`FixStr::try_fill`].
+#[cfg(not(feature = "panic-free"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillError {
+    /// The filled template does not fit.
+    Capacity(CapacityError),
+    /// The template has a different number of `{}` placeholders than there are arguments.
+    ArgumentCount {
+        /// Number of placeholders in the template.
+        placeholders: usize,
+        /// Number of arguments given.
+        arguments: usize,
+    },
+    /// A `{` or `}` at this octet offset is neither part of `{}` nor escaped as `{{` or `}}`.
+    UnmatchedBrace {
+        /// Octet offset of the brace in the template.
+        index: usize,
+    },
+}
+
+#[cfg(not(feature = "panic-free"))]
+impl fmt::Display for FillError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(err) => fmt::Display::fmt(err, f),
+            Self::ArgumentCount {
+                placeholders,
+                arguments,
+            } => write!(
+                f,
+                "Template has {placeholders} placeholders but {arguments} arguments were given"
+            ),
+            Self::UnmatchedBrace { index } => write!(f, "Unmatched brace at index {index}"),
+        }
+    }
+}
+
+#[cfg(not(feature = "panic-free"))]
+impl std::error::Error for FillError {}
+
+/// A template piece: literal text, or the position of a `{}` placeholder.
+#[cfg(not(feature = "panic-free"))]
+enum Piece<'a> {
+    Text(&'a str),
+    Placeholder,
+}
+
+/// Splits a template into pieces, failing at the first unmatched brace.
+#[cfg(not(feature = "panic-free"))]
+fn pieces(template: &str) -> impl Iterator<Item = Result<Piece<'_>, FillError>> {
+    let mut rest = template;
+    std::iter::from_fn(move || {
+        let index = template.len() - rest.len();
+        let brace = rest.find(['{', '}']);
+        let (piece, skip) = match brace {
+            Some(0) => match rest.get(..2) {
+                Some("{}") => (Piece::Placeholder, 2),
+                Some("{{") => (Piece::Text("{"), 2),
+                Some("}}") => (Piece::Text("}"), 2),
+                _ => {
+                    rest = "";
+                    return Some(Err(FillError::UnmatchedBrace { index }));
+                }
+            },
+            Some(end) => (Piece::Text(rest.get(..end).unwrap_or_default()), end),
+            None if rest.is_empty() => return None,
+            None => (Piece::Text(rest), rest.len()),
+        };
+        rest = rest.get(skip..).unwrap_or_default();
+        Some(Ok(piece))
+    })
+}
+
+/// Writes a template with its placeholders replaced by arguments.
+#[cfg(not(feature = "panic-free"))]
+struct Fill<'a> {
+    template: &'a str,
+    args: &'a [&'a dyn fmt::Display],
+}
+
+#[cfg(not(feature = "panic-free"))]
+impl fmt::Display for Fill<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut args = self.args.iter();
+        for piece in pieces(self.template).flatten() {
+            match piece {
+                Piece::Text(text) => f.write_str(text)?,
+                Piece::Placeholder => {
+                    if let Some(arg) = args.next() {
+                        arg.fmt(f)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> FixStr<N> {
+    /// Formats `args` into a new `FixStr`.
+    #[cfg(not(feature = "panic-free"))]
+    pub(crate) fn try_format(args: fmt::Arguments<'_>) -> Result<Self, CapacityError> {
+        let mut writer = FixStrWriter::new();
+        let _ = writer.write_fmt(args);
+        writer.finish()
+    }
+
+    /// Formats a float in scientific notation with `precision` digits after the decimal point,
+    /// like `format!("{value:.precision$e}")`: `from_f64_exp(0.000_123_4, 2)` gives `"1.23e-4"`.
+    ///
+    /// Not available with the `panic-free` feature, as float formatting in `core` keeps
+    /// panic paths.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the formatted number does not fit.
+    #[cfg(not(feature = "panic-free"))]
+    pub fn from_f64_exp(value: f64, precision: usize) -> Result<Self, CapacityError> {
+        Self::try_format(format_args!("{value:.precision$e}"))
+    }
+
+    /// Formats a float with an SI prefix and `precision` digits after the decimal point, so
+    /// `from_f64_si(1500.0, 1)` gives `"1.5k"` and `from_f64_si(0.0032, 1)` gives `"3.2m"`.
+    ///
+    /// Prefixes range from `p` (10⁻¹²) to `E` (10¹⁸), with `µ` for micro; values without a
+    /// prefix are formatted plainly.
+    ///
+    /// Not available with the `panic-free` feature, as float formatting in `core` keeps
+    /// panic paths.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the formatted number does not fit.
+    #[cfg(not(feature = "panic-free"))]
+    pub fn from_f64_si(value: f64, precision: usize) -> Result<Self, CapacityError> {
+        const PREFIXES: [&str; 11] = ["p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E"];
+        const UNPREFIXED: i32 = 4;
+
+        if value == 0.0 || !value.is_finite() {
+            return Self::try_format(format_args!("{value:.precision$}"));
+        }
+        let mut exponent = (value.abs().log10() / 3.0).floor() as i32;
+        exponent = exponent.clamp(-UNPREFIXED, PREFIXES.len() as i32 - 1 - UNPREFIXED);
+        let mut scaled = value / 1000_f64.powi(exponent);
+
+        // Rounding can carry into the next prefix, as with 999.96 at one decimal
+        let factor = 10_f64.powi(i32::try_from(precision).unwrap_or(i32::MAX));
+        if (scaled.abs() * factor).round() / factor >= 1000.0
+            && exponent < PREFIXES.len() as i32 - 1 - UNPREFIXED
+        {
+            exponent += 1;
+            scaled /= 1000.0;
+        }
+
+        let prefix = usize::try_from(exponent + UNPREFIXED)
+            .ok()
+            .and_then(|i| PREFIXES.get(i))
+            .unwrap_or(&"");
+        Self::try_format(format_args!("{scaled:.precision$}{prefix}"))
+    }
+
+    /// Formats a duration, truncated to whole seconds (or milliseconds for `Compact`
+    /// durations below a second).
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the formatted duration does not fit.
+    pub fn from_duration(duration: Duration, style: DurationStyle) -> Result<Self, CapacityError> {
+        let total = duration.as_secs();
+        let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+        let mut writer = FixStrWriter::new();
+        match style {
+            DurationStyle::Clock => {
+                writer.write_decimal(hours.into(), 2);
+                let _ = writer.write_str(":");
+                writer.write_decimal(minutes.into(), 2);
+                let _ = writer.write_str(":");
+                writer.write_decimal(seconds.into(), 2);
+            }
+            DurationStyle::Compact if total == 0 => {
+                writer.write_decimal(duration.subsec_millis().into(), 0);
+                let _ = writer.write_str("ms");
+            }
+            DurationStyle::Compact => {
+                let mut separator = "";
+                for (value, unit) in [(hours, "h"), (minutes, "m"), (seconds, "s")] {
+                    if value != 0 {
+                        let _ = writer.write_str(separator);
+                        writer.write_decimal(value.into(), 0);
+                        let _ = writer.write_str(unit);
+                        separator = " ";
+                    }
+                }
+            }
+        }
+        writer.finish()
+    }
+
+    /// Formats a size in octets with binary units and one decimal, such as `"1.2 MiB"`.
+    /// Sizes below 1 KiB are shown exactly, such as `"512 B"`.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the formatted size does not fit.
+    pub fn from_bytes_size(bytes: u64) -> Result<Self, CapacityError> {
+        const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+        let mut writer = FixStrWriter::new();
+        if bytes < 1024 {
+            writer.write_decimal(bytes.into(), 0);
+            let _ = writer.write_str(" B");
+            return writer.finish();
+        }
+        let mut unit = 0;
+        let mut tenths = tenths_of(bytes, 0);
+        // Move up while the size would round to 1024.0 or more at one decimal
+        while tenths >= 10240 && unit + 1 < UNITS.len() {
+            unit += 1;
+            tenths = tenths_of(bytes, unit);
+        }
+        writer.write_decimal(tenths / 10, 0);
+        let _ = writer.write_str(".");
+        writer.write_decimal(tenths % 10, 0);
+        let _ = writer.write_str(" ");
+        let _ = writer.write_str(UNITS.get(unit).unwrap_or(&"EiB"));
+        writer.finish()
+    }
+
+    /// Formats `numerator / denominator` as a percentage with `precision` digits after the
+    /// decimal point, such as `"87.5%"` for `from_ratio_percent(7, 8, 1)`.
+    ///
+    /// A zero denominator gives `"n/a"` instead of an infinite or NaN percentage.
+    ///
+    /// Not available with the `panic-free` feature, as float formatting in `core` keeps
+    /// panic paths.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the formatted percentage does not fit.
+    #[cfg(not(feature = "panic-free"))]
+    pub fn from_ratio_percent(
+        numerator: u64,
+        denominator: u64,
+        precision: usize,
+    ) -> Result<Self, CapacityError> {
+        if denominator == 0 {
+            return Self::try_format(format_args!("n/a"));
+        }
+        let percent = numerator as f64 / denominator as f64 * 100.0;
+        Self::try_format(format_args!("{percent:.precision$}%"))
+    }
+
+    /// Fills the `{}` placeholders of `template` with `args` in order, so
+    /// `try_fill("{} joined {}", &[&name, &team])` builds a message without allocating.
+    /// Literal braces are written as `{{` and `}}`.
+    ///
+    /// Not available with the `panic-free` feature, as the arguments' `Display` impls may
+    /// panic.
+    ///
+    /// # Errors
+    /// Returns `FillError` if the template is malformed, the number of placeholders and
+    /// arguments differ, or the result does not fit.
+    #[cfg(not(feature = "panic-free"))]
+    pub fn try_fill(template: &str, args: &[&dyn fmt::Display]) -> Result<Self, FillError> {
+        let mut placeholders = 0;
+        for piece in pieces(template) {
+            if let Piece::Placeholder = piece? {
+                placeholders += 1;
+            }
+        }
+        if placeholders != args.len() {
+            return Err(FillError::ArgumentCount {
+                placeholders,
+                arguments: args.len(),
+            });
+        }
+        Self::try_format(format_args!("{}", Fill { template, args })).map_err(FillError::Capacity)
+    }
+
+    /// Formats an integer in decimal, zero-padded to at least `width` chars, like
+    /// `format!("{value:0width$}")`: `from_int_padded(42, 5)` gives `"00042"` and
+    /// `from_int_padded(-42, 5)` gives `"-0042"`.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the formatted number does not fit.
+    pub fn from_int_padded(value: impl Into<i128>, width: usize) -> Result<Self, CapacityError> {
+        let value: i128 = value.into();
+        let mut writer = FixStrWriter::new();
+        let sign = if value < 0 { "-" } else { "" };
+        let _ = writer.write_str(sign);
+        writer.write_decimal(value.unsigned_abs(), width.saturating_sub(sign.len()));
+        writer.finish()
+    }
+}
+
+/// Returns `bytes / 1024^(unit + 1)` in tenths, so `unit` 0 counts KiB, rounded half to even
+/// like `{:.1}` formatting.
+fn tenths_of(bytes: u64, unit: usize) -> u128 {
+    let divisor = 1u128 << (10 * (unit + 1));
+    let scaled = u128::from(bytes) * 10;
+    let (quotient, remainder) = (scaled / divisor, scaled % divisor);
+    match (remainder * 2).cmp(&divisor) {
+        std::cmp::Ordering::Greater => quotient + 1,
+        std::cmp::Ordering::Equal => quotient + (quotient & 1),
+        std::cmp::Ordering::Less => quotient,
+    }
+}
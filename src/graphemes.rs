@@ -0,0 +1,24 @@
+use unicode_segmentation::{Graphemes, UnicodeSegmentation};
+
+use crate::FixStr;
+
+impl<const N: usize> FixStr<N> {
+    /// Returns an iterator over the extended grapheme clusters, the user-perceived
+    /// characters that a cursor should step over as a whole.
+    ///
+    /// ```
+    /// use fixstr::FixStr;
+    ///
+    /// let s: FixStr<32> = FixStr::new("e\u{301}👍🏽!").unwrap();
+    /// assert_eq!(s.graphemes().collect::<Vec<_>>(), ["e\u{301}", "👍🏽", "!"]);
+    /// ```
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        self.as_str().graphemes(true)
+    }
+
+    /// Returns the number of extended grapheme clusters.
+    #[must_use]
+    pub fn grapheme_len(&self) -> usize {
+        self.graphemes().count()
+    }
+}
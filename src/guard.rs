@@ -0,0 +1,104 @@
+use std::ops::{Deref, DerefMut};
+use std::str::Utf8Error;
+
+use crate::FixStr;
+
+impl<const N: usize> FixStr<N> {
+    /// Returns a mutable string slice, for in-place algorithms that keep the UTF-8 length.
+    #[must_use]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        let len = self.len();
+        let bytes = self.inline.get_mut(..len).unwrap_or_default();
+        // Always valid UTF-8, the default empty slice is never used
+        std::str::from_utf8_mut(bytes).unwrap_or_default()
+    }
+
+    /// Returns the unused tail of the buffer, for decoders that write directly into it before
+    /// committing the new length with [`set_len`](Self::set_len).
+    ///
+    /// Unlike `Vec::spare_capacity_mut` the octets are always initialized, so this is a plain
+    /// octet slice and writing to it is safe; the written octets stay invisible until
+    /// `set_len` is called, and are left in the buffer if it never is.
+    #[must_use]
+    pub fn spare_capacity_mut(&mut self) -> &mut [u8] {
+        let len = self.len();
+        let capacity = N.min(u8::MAX as usize);
+        self.inline.get_mut(len..capacity).unwrap_or_default()
+    }
+
+    /// Sets the length of the string in octets, clearing any octets past it.
+    ///
+    /// Not available with the `no-unsafe` feature.
+    ///
+    /// # Safety
+    /// `new_len` must not exceed the capacity (or 255), and the first `new_len` octets of the
+    /// buffer must be valid UTF-8.
+    #[cfg(not(feature = "no-unsafe"))]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= N.min(u8::MAX as usize));
+        self.len = new_len as u8;
+        for b in self.inline.iter_mut().skip(new_len) {
+            *b = 0;
+        }
+    }
+
+    /// Returns a guard giving mutable access to the used octets.
+    ///
+    /// The guard edits a copy that is validated when the guard is released: only if it is
+    /// still valid UTF-8 is it written back, otherwise the string keeps its value from before
+    /// the guard was created. Use [`BytesMutGuard::commit`] to find out which happened.
+    /// Leaking the guard with `mem::forget` discards the changes.
+    #[must_use]
+    pub fn bytes_mut_guard(&mut self) -> BytesMutGuard<'_, N> {
+        BytesMutGuard {
+            inline: self.inline,
+            s: self,
+        }
+    }
+}
+
+/// Mutable access to the octets of a `FixStr`, re-validated as UTF-8 on release.
+///
+/// Created by [`FixStr::bytes_mut_guard`].
+pub struct BytesMutGuard<'a, const N: usize> {
+    s: &'a mut FixStr<N>,
+    /// The copy being edited; the string itself is untouched until the copy validates.
+    inline: [u8; N],
+}
+
+impl<const N: usize> BytesMutGuard<'_, N> {
+    /// Releases the guard, keeping the changes if they are valid UTF-8.
+    ///
+    /// # Errors
+    /// Returns the `Utf8Error` if the octets are invalid, in which case the string keeps
+    /// its original value.
+    pub fn commit(self) -> Result<(), Utf8Error> {
+        self.validate()
+    }
+
+    fn validate(&self) -> Result<(), Utf8Error> {
+        crate::utf8::from_utf8(self).map(|_| ())
+    }
+}
+
+impl<const N: usize> Deref for BytesMutGuard<'_, N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.inline.get(..self.s.len()).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> DerefMut for BytesMutGuard<'_, N> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.inline.get_mut(..self.s.len()).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> Drop for BytesMutGuard<'_, N> {
+    fn drop(&mut self) {
+        if self.validate().is_ok() {
+            self.s.inline = self.inline;
+        }
+    }
+}
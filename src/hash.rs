@@ -0,0 +1,52 @@
+use crate::FixStr;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Computes the 64-bit FNV-1a hash of `s`.
+///
+/// This is the runtime counterpart of [`FixStr::hash_fnv1a`], for hashing incoming names
+/// that are not stored in a `FixStr`. Both always produce the same value for the same text.
+#[must_use]
+pub const fn fnv1a(s: &str) -> u64 {
+    let bytes = s.as_bytes();
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
+
+impl<const N: usize> FixStr<N> {
+    /// Computes the 64-bit FNV-1a hash of the string, usable in const contexts.
+    ///
+    /// Lets message-name hashes be computed at compile time and matched at runtime without
+    /// comparing strings:
+    ///
+    /// ```
+    /// use fixstr::{fixconcat, fnv1a, FixStr};
+    ///
+    /// const PING: FixStr<4> = fixconcat!("ping");
+    /// const PING_HASH: u64 = PING.hash_fnv1a();
+    ///
+    /// fn dispatch(name: &str) -> &'static str {
+    ///     match fnv1a(name) {
+    ///         PING_HASH => "pong",
+    ///         _ => "unknown",
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(dispatch("ping"), "pong");
+    /// assert_eq!(dispatch("pong"), "unknown");
+    /// ```
+    ///
+    /// FNV-1a is not collision resistant; do not use it for untrusted input where
+    /// collisions matter.
+    #[must_use]
+    pub const fn hash_fnv1a(&self) -> u64 {
+        fnv1a(self.as_str())
+    }
+}
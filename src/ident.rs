@@ -0,0 +1,27 @@
+use crate::FixStr;
+
+const fn is_ident(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if !(b == b'_' || b.is_ascii_alphabetic() || (i > 0 && b.is_ascii_digit())) {
+            return false;
+        }
+        i += 1;
+    }
+    !bytes.is_empty()
+}
+
+crate::define_fixstr!(
+    /// A `FixStr` that holds an identifier matching `[A-Za-z_][A-Za-z0-9_]*`.
+    pub IdentFixStr<N>, N, const is_ident
+);
+
+impl<const N: usize> IdentFixStr<N> {
+    /// Wraps `s` if it is an identifier.
+    #[must_use]
+    pub fn from_fixstr(s: FixStr<N>) -> Option<Self> {
+        is_ident(s.as_str()).then_some(Self(s))
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::iter::{FusedIterator, Zip};
+use std::ops::RangeFrom;
+use std::slice;
+
+use crate::{CapacityError, FixStr};
+
+/// Error returned by [`FixStrInterner::intern_str`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InternError {
+    /// The string does not fit in the interner's `FixStr<N>`.
+    Capacity(CapacityError),
+    /// All `u32::MAX + 1` symbols have been handed out.
+    Full,
+}
+
+impl fmt::Display for InternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(err) => fmt::Display::fmt(err, f),
+            Self::Full => f.write_str("Interner has run out of symbols"),
+        }
+    }
+}
+
+impl std::error::Error for InternError {}
+
+impl From<CapacityError> for InternError {
+    fn from(err: CapacityError) -> Self {
+        Self::Capacity(err)
+    }
+}
+
+/// A small integer handle for a string stored in a [`FixStrInterner`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the position of the string in the interner, in insertion order.
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Deduplicates strings into [`Symbol`] handles that can be resolved back to `FixStr<N>`.
+///
+/// Symbols are handed out in insertion order starting from zero, and stay valid for the
+/// lifetime of the interner.
+#[derive(Clone, Debug, Default)]
+pub struct FixStrInterner<const N: usize> {
+    strings: Vec<FixStr<N>>,
+    symbols: HashMap<FixStr<N>, Symbol>,
+}
+
+impl<const N: usize> FixStrInterner<N> {
+    /// Creates an empty interner.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty interner with room for `capacity` strings before reallocating.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            strings: Vec::with_capacity(capacity),
+            symbols: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the symbol for `s`, adding it if it has not been interned before.
+    ///
+    /// Returns `None` if `s` is new and all `u32::MAX + 1` symbols have been handed out.
+    #[must_use]
+    pub fn try_intern(&mut self, s: FixStr<N>) -> Option<Symbol> {
+        if let Some(&symbol) = self.symbols.get(&s) {
+            return Some(symbol);
+        }
+        let symbol = Symbol(u32::try_from(self.strings.len()).ok()?);
+        self.strings.push(s);
+        self.symbols.insert(s, symbol);
+        Some(symbol)
+    }
+
+    /// Returns the symbol for `s`, adding it if it has not been interned before.
+    ///
+    /// Not available with the `panic-free` feature.
+    ///
+    /// # Panics
+    /// Panics if more than `u32::MAX + 1` distinct strings are interned.
+    #[cfg(not(feature = "panic-free"))]
+    pub fn intern(&mut self, s: FixStr<N>) -> Symbol {
+        self.try_intern(s)
+            .unwrap_or_else(|| panic!("interner exceeds {} symbols", u64::from(u32::MAX) + 1))
+    }
+
+    /// Returns the symbol for `s`, adding it if it has not been interned before.
+    ///
+    /// # Errors
+    /// Returns `InternError::Capacity` if `s` does not fit in a `FixStr<N>` and
+    /// `InternError::Full` if it is new and all symbols have been handed out.
+    pub fn intern_str(&mut self, s: &str) -> Result<Symbol, InternError> {
+        let s = FixStr::try_new(s)?;
+        self.try_intern(s).ok_or(InternError::Full)
+    }
+
+    /// Returns the symbol for `s` if it has been interned.
+    #[must_use]
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.symbols.get(&FixStr::new(s)?).copied()
+    }
+
+    /// Returns the string for `symbol`, or `None` if its index is past the interned strings.
+    ///
+    /// Symbols are plain indices, so one from another interner resolves to whichever string
+    /// has the same index here.
+    #[must_use]
+    pub fn resolve(&self, symbol: Symbol) -> Option<FixStr<N>> {
+        self.strings.get(symbol.index()).copied()
+    }
+
+    /// Returns the number of distinct strings interned.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if no strings have been interned.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns an iterator over the symbols and their strings in insertion order.
+    pub fn iter(&self) -> InternerIter<'_, N> {
+        InternerIter {
+            inner: self.strings.iter().zip(0..),
+        }
+    }
+}
+
+/// Iterator returned by [`FixStrInterner::iter`].
+#[derive(Clone, Debug)]
+pub struct InternerIter<'a, const N: usize> {
+    inner: Zip<slice::Iter<'a, FixStr<N>>, RangeFrom<u32>>,
+}
+
+impl<const N: usize> Iterator for InternerIter<'_, N> {
+    type Item = (Symbol, FixStr<N>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(&s, index)| (Symbol(index), s))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<const N: usize> FusedIterator for InternerIter<'_, N> {}
@@ -0,0 +1,20 @@
+use crate::FixStr;
+use ::ascii::{AsAsciiStrError, AsciiStr};
+
+impl<const N: usize> FixStr<N> {
+    /// Returns the string as an `AsciiStr` view.
+    ///
+    /// # Errors
+    /// Returns `AsAsciiStrError` if the string contains non-ASCII characters.
+    pub fn as_ascii_str(&self) -> Result<&AsciiStr, AsAsciiStrError> {
+        AsciiStr::from_ascii(self.as_bytes())
+    }
+}
+
+impl<const N: usize> TryFrom<&AsciiStr> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(s: &AsciiStr) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::FixStr;
+use ::bson::Bson;
+
+impl<const N: usize> From<FixStr<N>> for Bson {
+    fn from(s: FixStr<N>) -> Self {
+        Bson::String(s.into())
+    }
+}
+
+impl<const N: usize> TryFrom<&Bson> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(value: &Bson) -> Result<Self, Self::Error> {
+        match value {
+            Bson::String(s) => Self::try_from(s.as_str()),
+            other => Err(format!(
+                "expected a BSON string, found {:?}",
+                other.element_type()
+            )),
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<Bson> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(value: Bson) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
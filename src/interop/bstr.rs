@@ -0,0 +1,29 @@
+use crate::FixStr;
+use ::bstr::BStr;
+
+impl<const N: usize> FixStr<N> {
+    /// Returns the string as a `BStr`, giving access to the `bstr::ByteSlice` algorithms
+    /// (`find`, `fields`, `trim` and friends) directly over the inline buffer.
+    #[must_use]
+    pub fn as_bstr(&self) -> &BStr {
+        BStr::new(self.as_bytes())
+    }
+}
+
+impl<const N: usize> AsRef<BStr> for FixStr<N> {
+    fn as_ref(&self) -> &BStr {
+        self.as_bstr()
+    }
+}
+
+impl<const N: usize> PartialEq<BStr> for FixStr<N> {
+    fn eq(&self, other: &BStr) -> bool {
+        self.as_bytes() == other.as_ref() as &[u8]
+    }
+}
+
+impl<const N: usize> PartialEq<FixStr<N>> for BStr {
+    fn eq(&self, other: &FixStr<N>) -> bool {
+        other == self
+    }
+}
@@ -0,0 +1,42 @@
+use crate::FixStr;
+use ::clap::builder::{TypedValueParser, ValueParserFactory};
+use ::clap::error::ErrorKind;
+use ::clap::{Arg, Command, Error};
+use std::ffi::OsStr;
+
+/// Parses command line values into `FixStr<N>`, rejecting overlong values with a
+/// "value too long (max N bytes)" error.
+///
+/// Used automatically by `clap::value_parser!(FixStr<N>)` and by derived arguments.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FixStrValueParser<const N: usize>;
+
+impl<const N: usize> TypedValueParser for FixStrValueParser<N> {
+    type Value = FixStr<N>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        FixStr::new(value).ok_or_else(|| {
+            let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+            cmd.clone().error(
+                ErrorKind::ValueValidation,
+                format!("invalid value '{value}' for '{arg}': value too long (max {N} bytes)"),
+            )
+        })
+    }
+}
+
+impl<const N: usize> ValueParserFactory for FixStr<N> {
+    type Parser = FixStrValueParser<N>;
+
+    fn value_parser() -> Self::Parser {
+        FixStrValueParser
+    }
+}
@@ -0,0 +1,28 @@
+//! `FixStr<N>` maps to `Text`, so it can be used for `VARCHAR(N)` columns on every backend.
+//! The `AsExpression` and `FromSqlRow` derives are on the type itself.
+
+use crate::FixStr;
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::serialize::{self, Output, ToSql};
+use ::diesel::sql_types::Text;
+
+impl<DB: Backend, const N: usize> ToSql<Text, DB> for FixStr<N>
+where
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <str as ToSql<Text, DB>>::to_sql(self.as_str(), out)
+    }
+}
+
+/// Fails if the stored text exceeds the capacity.
+impl<DB: Backend, const N: usize> FromSql<Text, DB> for FixStr<N>
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let s = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(Self::try_from(s)?)
+    }
+}
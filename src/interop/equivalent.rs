@@ -0,0 +1,9 @@
+use crate::FixStr;
+use ::equivalent::Equivalent;
+
+/// Allows `hashbrown` and `indexmap` maps keyed by `FixStr` to be queried with a `&str`.
+impl<const N: usize> Equivalent<FixStr<N>> for str {
+    fn equivalent(&self, key: &FixStr<N>) -> bool {
+        self == key.as_str()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::FixStr;
+use ::http::header::{HeaderValue, InvalidHeaderValue};
+
+/// Fails if the header value is not visible ASCII or does not fit in the capacity.
+impl<const N: usize> TryFrom<&HeaderValue> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(value: &HeaderValue) -> Result<Self, Self::Error> {
+        let s = value
+            .to_str()
+            .map_err(|_| format!("Header value {value:?} is not visible ASCII"))?;
+        Self::try_from(s)
+    }
+}
+
+impl<const N: usize> TryFrom<FixStr<N>> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    fn try_from(s: FixStr<N>) -> Result<Self, Self::Error> {
+        HeaderValue::from_str(s.as_str())
+    }
+}
@@ -0,0 +1,75 @@
+//! Conversions to and from string types of other crates, each behind its own feature.
+
+#[cfg(feature = "smol_str")]
+mod smol_str;
+
+#[cfg(feature = "smartstring")]
+mod smartstring;
+
+#[cfg(feature = "tinystr")]
+mod tinystr;
+
+#[cfg(feature = "bstr")]
+mod bstr;
+
+#[cfg(feature = "ascii")]
+mod ascii;
+
+#[cfg(feature = "equivalent")]
+mod equivalent;
+
+// Implementing the wasm-bindgen traits requires `unsafe`
+#[cfg(all(feature = "wasm", not(feature = "no-unsafe")))]
+mod wasm;
+
+#[cfg(feature = "pyo3")]
+pub(crate) mod pyo3;
+
+#[cfg(feature = "uniffi")]
+mod uniffi;
+
+// Implementing the napi traits requires `unsafe`
+#[cfg(all(feature = "napi", not(feature = "no-unsafe")))]
+mod napi;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "diesel")]
+mod diesel;
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
+#[cfg(feature = "redis")]
+mod redis;
+
+#[cfg(feature = "bson")]
+mod bson;
+
+#[cfg(feature = "valuable")]
+mod valuable;
+
+#[cfg(feature = "slog")]
+mod slog;
+
+#[cfg(feature = "http")]
+mod http;
+
+#[cfg(feature = "clap")]
+pub(crate) mod clap;
+
+#[cfg(feature = "phf")]
+mod phf;
+
+#[cfg(feature = "subtle")]
+mod subtle;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "serde_json")]
+mod serde_json;
@@ -0,0 +1,38 @@
+use crate::FixStr;
+use ::napi::bindgen_prelude::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
+use ::napi::{sys, Error, Result, Status, ValueType};
+
+impl<const N: usize> TypeName for FixStr<N> {
+    fn type_name() -> &'static str {
+        "FixStr"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::String
+    }
+}
+
+impl<const N: usize> ValidateNapiValue for FixStr<N> {}
+
+impl<const N: usize> ToNapiValue for FixStr<N> {
+    unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+        // SAFETY: forwarded from the caller
+        unsafe { <&str as ToNapiValue>::to_napi_value(env, val.as_str()) }
+    }
+}
+
+impl<const N: usize> ToNapiValue for &FixStr<N> {
+    unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+        // SAFETY: forwarded from the caller
+        unsafe { <&str as ToNapiValue>::to_napi_value(env, val.as_str()) }
+    }
+}
+
+/// Fails with `Status::InvalidArg` if the JavaScript string exceeds the capacity.
+impl<const N: usize> FromNapiValue for FixStr<N> {
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+        // SAFETY: forwarded from the caller
+        let s = unsafe { String::from_napi_value(env, napi_val)? };
+        Self::try_from(s).map_err(|reason| Error::new(Status::InvalidArg, reason))
+    }
+}
@@ -0,0 +1,23 @@
+use crate::FixStr;
+use ::phf_shared::{PhfBorrow, PhfHash};
+use std::hash::Hasher;
+
+/// Hashes the same way as the contained `str`, so `phf` maps keyed by `FixStr` can be
+/// queried with plain string keys.
+impl<const N: usize> PhfHash for FixStr<N> {
+    fn phf_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().phf_hash(state);
+    }
+}
+
+impl<const N: usize> PhfBorrow<str> for FixStr<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PhfBorrow<FixStr<N>> for FixStr<N> {
+    fn borrow(&self) -> &FixStr<N> {
+        self
+    }
+}
@@ -0,0 +1,42 @@
+use crate::FixStr;
+use ::pyo3::exceptions::PyValueError;
+use ::pyo3::prelude::*;
+use ::pyo3::types::PyString;
+use std::convert::Infallible;
+
+::pyo3::create_exception!(
+    fixstr,
+    PyCapacityExceededError,
+    PyValueError,
+    "Raised when a Python str does not fit in the capacity of a FixStr."
+);
+
+impl<'py, const N: usize> IntoPyObject<'py> for FixStr<N> {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(PyString::new(py, self.as_str()))
+    }
+}
+
+impl<'py, const N: usize> IntoPyObject<'py> for &FixStr<N> {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(PyString::new(py, self.as_str()))
+    }
+}
+
+/// Extracts from a Python `str`, raising `PyCapacityExceededError` if it does not fit.
+impl<const N: usize> FromPyObject<'_, '_> for FixStr<N> {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> Result<Self, Self::Error> {
+        let py_str = obj.cast::<PyString>()?;
+        Self::try_from(py_str.to_cow()?.as_ref()).map_err(PyCapacityExceededError::new_err)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::FixStr;
+use ::redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+impl<const N: usize> ToRedisArgs for FixStr<N> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.as_bytes());
+    }
+}
+
+impl<const N: usize> ToSingleRedisArg for FixStr<N> {}
+
+/// Fails if the value is not a string or does not fit in the capacity.
+impl<const N: usize> FromRedisValue for FixStr<N> {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        let s = String::from_redis_value_ref(v)?;
+        Self::try_new(&s).map_err(|err| err.to_string().into())
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+}
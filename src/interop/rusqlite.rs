@@ -0,0 +1,17 @@
+use crate::FixStr;
+use ::rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use ::rusqlite::Result;
+
+impl<const N: usize> ToSql for FixStr<N> {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Text(self.as_bytes())))
+    }
+}
+
+/// Fails with a `CapacityError` wrapped in `FromSqlError::Other` if the TEXT does not fit.
+impl<const N: usize> FromSql for FixStr<N> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let s = value.as_str()?;
+        Self::try_new(s).map_err(FromSqlError::other)
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use crate::{AlignedFixStr, Alignment, FixStr, FixStrList, NonEmptyFixStr};
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use ::serde::ser::SerializeSeq;
+use ::serde::{Serialize, Serializer};
+
+impl<const N: usize> Serialize for FixStr<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct FixStrVisitor<const N: usize>;
+
+impl<const N: usize> Visitor<'_> for FixStrVisitor<N> {
+    type Value = FixStr<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string of at most {N} octets")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        FixStr::try_from(v).map_err(E::custom)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for FixStr<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FixStrVisitor)
+    }
+}
+
+impl<const N: usize, A: Alignment> Serialize for AlignedFixStr<N, A> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, const N: usize, A: Alignment> Deserialize<'de> for AlignedFixStr<N, A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FixStr::deserialize(deserializer).map(Self::from_fixstr)
+    }
+}
+
+impl<const N: usize> Serialize for NonEmptyFixStr<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for NonEmptyFixStr<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = FixStr::deserialize(deserializer)?;
+        Self::try_from(s).map_err(de::Error::custom)
+    }
+}
+
+impl<const N: usize, const K: usize> Serialize for FixStrList<N, K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for s in self {
+            seq.serialize_element(s)?;
+        }
+        seq.end()
+    }
+}
+
+struct FixStrListVisitor<const N: usize, const K: usize>;
+
+impl<'de, const N: usize, const K: usize> Visitor<'de> for FixStrListVisitor<N, K> {
+    type Value = FixStrList<N, K>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "at most {K} strings of at most {N} octets")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = FixStrList::new();
+        while let Some(s) = seq.next_element()? {
+            if list.is_full() {
+                return Err(de::Error::invalid_length(K + 1, &self));
+            }
+            list.push(s);
+        }
+        Ok(list)
+    }
+}
+
+impl<'de, const N: usize, const K: usize> Deserialize<'de> for FixStrList<N, K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(FixStrListVisitor)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::FixStr;
+use ::serde_json::Value;
+
+impl<const N: usize> From<FixStr<N>> for Value {
+    fn from(s: FixStr<N>) -> Self {
+        Value::String(s.into())
+    }
+}
+
+impl<const N: usize> TryFrom<&Value> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(s) => Self::try_from(s.as_str()),
+            Value::Null => Err("expected a JSON string, found null".to_string()),
+            Value::Bool(_) => Err("expected a JSON string, found a boolean".to_string()),
+            Value::Number(_) => Err("expected a JSON string, found a number".to_string()),
+            Value::Array(_) => Err("expected a JSON string, found an array".to_string()),
+            Value::Object(_) => Err("expected a JSON string, found an object".to_string()),
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<Value> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
@@ -0,0 +1,8 @@
+use crate::FixStr;
+use ::slog::{Key, Record, Result, Serializer, Value};
+
+impl<const N: usize> Value for FixStr<N> {
+    fn serialize(&self, _record: &Record<'_>, key: Key, serializer: &mut dyn Serializer) -> Result {
+        serializer.emit_str(key, self.as_str())
+    }
+}
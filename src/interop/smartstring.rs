@@ -0,0 +1,24 @@
+use crate::FixStr;
+use ::smartstring::{SmartString, SmartStringMode};
+
+impl<const N: usize, Mode: SmartStringMode> From<FixStr<N>> for SmartString<Mode> {
+    fn from(s: FixStr<N>) -> Self {
+        SmartString::from(s.as_str())
+    }
+}
+
+impl<const N: usize, Mode: SmartStringMode> TryFrom<&SmartString<Mode>> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(s: &SmartString<Mode>) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl<const N: usize, Mode: SmartStringMode> TryFrom<SmartString<Mode>> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(s: SmartString<Mode>) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
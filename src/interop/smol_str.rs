@@ -0,0 +1,24 @@
+use crate::FixStr;
+use ::smol_str::SmolStr;
+
+impl<const N: usize> From<FixStr<N>> for SmolStr {
+    fn from(s: FixStr<N>) -> Self {
+        SmolStr::new(s.as_str())
+    }
+}
+
+impl<const N: usize> TryFrom<&SmolStr> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(s: &SmolStr) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl<const N: usize> TryFrom<SmolStr> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(s: SmolStr) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
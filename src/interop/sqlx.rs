@@ -0,0 +1,40 @@
+//! `FixStr` maps to the same SQL type as `str` (TEXT/VARCHAR) for every sqlx driver.
+//! Values read back from the database are checked against the capacity when decoding.
+
+use crate::FixStr;
+use ::sqlx::decode::Decode;
+use ::sqlx::encode::{Encode, IsNull};
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Type};
+
+impl<DB: Database, const N: usize> Type<DB> for FixStr<N>
+where
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database, const N: usize> Encode<'q, DB> for FixStr<N>
+where
+    for<'a> &'a str: Encode<'a, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<DB>>::encode(self.as_str(), buf)
+    }
+}
+
+impl<'r, DB: Database, const N: usize> Decode<'r, DB> for FixStr<N>
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let s = <&str as Decode<DB>>::decode(value)?;
+        Ok(Self::try_from(s)?)
+    }
+}
@@ -0,0 +1,8 @@
+use crate::FixStr;
+use ::subtle::{Choice, ConstantTimeEq};
+
+impl<const N: usize> ConstantTimeEq for FixStr<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.ct_diff(other).ct_eq(&0)
+    }
+}
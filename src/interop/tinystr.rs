@@ -0,0 +1,19 @@
+use crate::FixStr;
+use ::tinystr::{ParseError, TinyAsciiStr};
+
+impl<const N: usize, const M: usize> TryFrom<FixStr<N>> for TinyAsciiStr<M> {
+    type Error = ParseError;
+
+    /// Fails if the string is not ASCII, contains NUL or is longer than `M` octets.
+    fn try_from(s: FixStr<N>) -> Result<Self, Self::Error> {
+        TinyAsciiStr::try_from_str(s.as_str())
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<TinyAsciiStr<M>> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(s: TinyAsciiStr<M>) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
@@ -0,0 +1,35 @@
+/// Declares a `FixStr<N>` type alias and registers it as a UniFFI custom type.
+///
+/// The alias crosses the FFI boundary as an ordinary `String`. Lifting a string that
+/// exceeds the capacity fails with an error instead of truncating, so the length limit
+/// is enforced on the Rust side.
+///
+/// Must be invoked in the crate that calls `uniffi::setup_scaffolding!()`. Since the
+/// registration is done for `FixStr<N>` itself, each capacity can only be declared once
+/// per crate.
+///
+/// # Examples
+///
+/// ```ignore
+/// uniffi::setup_scaffolding!();
+///
+/// fixstr::uniffi_fixstr!(pub PlayerName, 24);
+///
+/// #[derive(uniffi::Record)]
+/// pub struct Player {
+///     pub name: PlayerName,
+/// }
+/// ```
+#[macro_export]
+macro_rules! uniffi_fixstr {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $capacity:expr) => {
+        $(#[$meta])*
+        $vis type $name = $crate::FixStr<$capacity>;
+
+        ::uniffi::custom_type!($name, String, {
+            remote,
+            lower: |s| String::from(s),
+            try_lift: |s| $crate::FixStr::try_from(s).map_err(::uniffi::deps::anyhow::Error::msg),
+        });
+    };
+}
@@ -0,0 +1,14 @@
+use crate::FixStr;
+use ::valuable::{Valuable, Value, Visit};
+
+/// Records as a plain string, so `tracing` (with `valuable` support) shows the content
+/// instead of the `Debug` output.
+impl<const N: usize> Valuable for FixStr<N> {
+    fn as_value(&self) -> Value<'_> {
+        Value::String(self.as_str())
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        visit.visit_value(self.as_value());
+    }
+}
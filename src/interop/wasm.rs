@@ -0,0 +1,68 @@
+//! Lets `FixStr` cross the JavaScript boundary as an ordinary JS string, both as a
+//! `JsValue` and as a field or argument type in `#[wasm_bindgen]` items.
+
+use crate::FixStr;
+use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi};
+use wasm_bindgen::describe::WasmDescribe;
+use wasm_bindgen::JsValue;
+
+impl<const N: usize> From<FixStr<N>> for JsValue {
+    fn from(s: FixStr<N>) -> Self {
+        JsValue::from_str(s.as_str())
+    }
+}
+
+impl<const N: usize> TryFrom<&JsValue> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(value: &JsValue) -> Result<Self, Self::Error> {
+        let s = value
+            .as_string()
+            .ok_or_else(|| format!("JsValue {value:?} is not a string"))?;
+        Self::try_from(s)
+    }
+}
+
+impl<const N: usize> TryFrom<JsValue> for FixStr<N> {
+    type Error = String;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const N: usize> WasmDescribe for FixStr<N> {
+    fn describe() {
+        <String as WasmDescribe>::describe();
+    }
+}
+
+impl<const N: usize> IntoWasmAbi for FixStr<N> {
+    type Abi = <String as IntoWasmAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        String::from(self).into_abi()
+    }
+}
+
+impl<const N: usize> OptionIntoWasmAbi for FixStr<N> {
+    fn none() -> Self::Abi {
+        <String as OptionIntoWasmAbi>::none()
+    }
+}
+
+impl<const N: usize> FromWasmAbi for FixStr<N> {
+    type Abi = <String as FromWasmAbi>::Abi;
+
+    /// Throws a JS exception if the incoming string exceeds the capacity.
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        let s = String::from_abi(js);
+        Self::try_from(s.as_str()).unwrap_or_else(|err| wasm_bindgen::throw_str(&err))
+    }
+}
+
+impl<const N: usize> OptionFromWasmAbi for FixStr<N> {
+    fn is_none(abi: &Self::Abi) -> bool {
+        <String as OptionFromWasmAbi>::is_none(abi)
+    }
+}
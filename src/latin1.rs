@@ -0,0 +1,109 @@
+use std::fmt;
+
+use crate::{CapacityError, FixStr};
+
+/// Error returned by [`FixStr::encode_latin1_into`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The encoded text does not fit in the buffer.
+    Capacity(CapacityError),
+    /// A char above U+00FF has no Latin-1 encoding.
+    Unmappable {
+        /// The char that cannot be encoded.
+        ch: char,
+        /// Octet offset of the char in the string.
+        index: usize,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(err) => fmt::Display::fmt(err, f),
+            Self::Unmappable { ch, index } => write!(
+                f,
+                "Character {ch:?} at index {index} has no Latin-1 encoding"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Windows-1252 characters for the octets 0x80 to 0x9F, where it differs from Latin-1.
+///
+/// The five unassigned octets decode to the C1 control of the same value, as in the
+/// WHATWG Encoding Standard.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+fn windows1252_char(b: u8) -> char {
+    match b {
+        0x80..=0x9F => WINDOWS_1252_HIGH
+            .get(usize::from(b - 0x80))
+            .copied()
+            .unwrap_or_default(),
+        _ => char::from(b),
+    }
+}
+
+impl<const N: usize> FixStr<N> {
+    /// Decodes ISO-8859-1 (Latin-1) text, where every octet is the code point of the
+    /// same value.
+    ///
+    /// Octets from 0x80 up take two octets in UTF-8, so the result can be up to twice as
+    /// long as the input.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the decoded text does not fit.
+    pub fn from_latin1(bytes: &[u8]) -> Result<Self, CapacityError> {
+        Self::try_from_chars(bytes.iter().copied().map(char::from))
+    }
+
+    /// Decodes Windows-1252 text, the superset of Latin-1 used by most legacy Western
+    /// European files, which assigns typographic characters such as `€` and `’` to
+    /// 0x80 to 0x9F.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the decoded text does not fit.
+    pub fn from_windows1252(bytes: &[u8]) -> Result<Self, CapacityError> {
+        Self::try_from_chars(bytes.iter().copied().map(windows1252_char))
+    }
+
+    /// Encodes the string as Latin-1 into `buffer` and returns the number of octets written.
+    ///
+    /// Every char takes exactly one octet, so the written length is `char_len()`.
+    ///
+    /// # Errors
+    /// Returns `EncodeError::Unmappable` for the first char above U+00FF and
+    /// `EncodeError::Capacity` if `buffer` is too short. `buffer` is left untouched on error.
+    pub fn encode_latin1_into(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        if let Some((index, ch)) = self.char_indices().find(|&(_, c)| u8::try_from(c).is_err()) {
+            return Err(EncodeError::Unmappable { ch, index });
+        }
+        self.encode_latin1_lossy_into(buffer)
+            .map_err(EncodeError::Capacity)
+    }
+
+    /// Encodes the string as Latin-1 into `buffer`, writing `?` for chars above U+00FF, and
+    /// returns the number of octets written.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if `buffer` is too short, in which case `buffer` is left
+    /// untouched.
+    pub fn encode_latin1_lossy_into(&self, buffer: &mut [u8]) -> Result<usize, CapacityError> {
+        let required = self.char_len();
+        if required > buffer.len() {
+            return Err(CapacityError {
+                len: required,
+                capacity: buffer.len(),
+            });
+        }
+        for (c, target) in self.chars().zip(buffer.iter_mut()) {
+            *target = u8::try_from(c).unwrap_or(b'?');
+        }
+        Ok(required)
+    }
+}
@@ -15,16 +15,134 @@
 //! let message: String = tiny2.into();
 //! ```
 
+#![cfg_attr(feature = "no-unsafe", forbid(unsafe_code))]
+
 /// A fixed-capacity string stored on the stack.
 ///
 /// `FixStr<N>` stores up to N octets inline and guarantees valid UTF-8.
 /// Useful for small strings where heap allocation is undesirable.
+use std::cmp::Ordering;
 use std::fmt;
-use std::fmt::{Debug, Display};
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
-#[derive(Clone, Copy, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub mod aliases;
+mod aligned;
+mod ascii_str;
+mod atomic;
+mod bulk;
+mod byte_str;
+mod c_str;
+mod case;
+mod caseless;
+mod chars;
+#[cfg(feature = "collation")]
+mod collation;
+mod compare;
+mod concat;
+mod ct;
+mod define;
+#[cfg(feature = "edit-distance")]
+mod distance;
+mod dns;
+#[cfg(feature = "alloc")]
+mod flex;
+mod format;
+#[cfg(feature = "unicode-segmentation")]
+mod graphemes;
+mod guard;
+mod hash;
+mod ident;
+#[cfg(feature = "alloc")]
+mod interner;
+mod interop;
+#[cfg(feature = "latin1")]
+mod latin1;
+mod list;
+mod locale;
+mod lossy;
+mod map;
+mod maybe;
+mod non_empty;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
+#[cfg(feature = "punycode")]
+mod punycode;
+mod search;
+mod set;
+#[cfg(feature = "symbols")]
+pub mod symbols;
+mod tokenizer;
+mod too_long;
+mod transform;
+mod utf16;
+mod utf8;
+mod version;
+mod wide;
+mod widen;
+#[cfg(feature = "unicode-width")]
+mod width;
+
+pub use aligned::{Align16, Align32, Align64, Align8, AlignedFixStr, Alignment};
+pub use ascii_str::FixAsciiStr;
+pub use atomic::AtomicFixStr;
+pub use byte_str::FixByteStr;
+pub use c_str::FixCString;
+pub use caseless::CaselessFixStr;
+pub use define::ValidationError;
+pub use dns::{DnsLabel, Hostname};
+#[cfg(feature = "alloc")]
+pub use flex::FlexStr;
+pub use format::DurationStyle;
+#[cfg(not(feature = "panic-free"))]
+pub use format::FillError;
+pub use guard::BytesMutGuard;
+pub use hash::fnv1a;
+pub use ident::IdentFixStr;
+#[cfg(feature = "alloc")]
+pub use interner::{FixStrInterner, InternError, InternerIter, Symbol};
+#[cfg(feature = "latin1")]
+pub use latin1::EncodeError;
+pub use list::FixStrList;
+pub use locale::{CountryCode, LangTag};
+pub use lossy::LossyStats;
+pub use map::{FixStrMap, MapIter};
+pub use maybe::MaybeFixStr;
+pub use non_empty::NonEmptyFixStr;
+#[cfg(feature = "punycode")]
+pub use punycode::PunycodeError;
+pub use search::{LinesFix, MatchIndices, Matches, Needle, SplitFix, SplitWhitespaceFix};
+pub use set::FixStrSet;
+pub use tokenizer::Tokenizer;
+pub use too_long::TooLongError;
+pub use version::{Components, VersionFixStr};
+pub use wide::WideFixStr;
+pub use widen::WidenInto;
+
+#[cfg(feature = "clap")]
+pub use interop::clap::FixStrValueParser;
+#[cfg(feature = "pyo3")]
+pub use interop::pyo3::PyCapacityExceededError;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::concat::{concat, concat_len};
+    pub use crate::define::new_const;
+    #[cfg(feature = "serde")]
+    pub use ::serde;
+}
+
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
 pub struct FixStr<const N: usize> {
+    /// The first `len` octets are valid UTF-8. Octets past `len` are zero when created
+    /// through the constructors, but may hold anything written through
+    /// `spare_capacity_mut` and never committed with `set_len`, so only `..len` may be read.
     inline: [u8; N],
     len: u8,
     _marker: PhantomData<[u8; N]>,
@@ -36,6 +154,37 @@ impl<const N: usize> Debug for FixStr<N> {
     }
 }
 
+/// Compares the lengths first and then only the used octets, which lowers to a single
+/// `memcmp`/`bcmp` call that is vectorized by the platform, instead of comparing all
+/// `N` octets of the buffer.
+impl<const N: usize> PartialEq for FixStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Eq for FixStr<N> {}
+
+/// Orders the same way as the contained `str`.
+impl<const N: usize> Ord for FixStr<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl<const N: usize> PartialOrd for FixStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hashes the same way as the contained `str`, so lookups can be made with plain string keys.
+impl<const N: usize> Hash for FixStr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
 impl<const N: usize> Default for FixStr<N> {
     fn default() -> Self {
         Self {
@@ -49,7 +198,8 @@ impl<const N: usize> Default for FixStr<N> {
 impl<const N: usize> FixStr<N> {
     /// Creates a new `FixStr` if the input fits within capacity.
     ///
-    /// Returns `None` if the string is too long (> N octets) or exceeds `u8::MAX`.
+    /// Returns `None` if the string is too long (> N octets) or exceeds `u8::MAX`; use
+    /// `WideFixStr` for capacities above 255.
     #[must_use]
     pub fn new(s: &str) -> Option<Self> {
         if s.len() > N || s.len() > u8::MAX as usize {
@@ -69,10 +219,103 @@ impl<const N: usize> FixStr<N> {
         })
     }
 
+    /// Creates a new `FixStr`, reporting the lengths involved when the input does not fit.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the string is too long (> N octets) or exceeds `u8::MAX`.
+    pub fn try_new(s: &str) -> Result<Self, CapacityError> {
+        Self::new(s).ok_or(CapacityError {
+            len: s.len(),
+            capacity: N,
+        })
+    }
+
+    /// Copies the string into a `FixStr` of capacity `M`.
+    ///
+    /// Always succeeds when `M >= N`.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the string does not fit in `M` octets.
+    pub fn resize<const M: usize>(&self) -> Result<FixStr<M>, CapacityError> {
+        FixStr::try_new(self.as_str())
+    }
+
+    /// Creates a new `FixStr` in const context, returning `None` if `s` does not fit.
+    pub(crate) const fn new_const(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        if bytes.len() > N || bytes.len() > u8::MAX as usize {
+            return None;
+        }
+        let mut inline = [0; N];
+        let mut i = 0;
+        while i < bytes.len() {
+            inline[i] = bytes[i];
+            i += 1;
+        }
+        Some(Self {
+            inline,
+            len: bytes.len() as u8,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Creates a `FixStr` from a string already known to fit, such as a slice of another
+    /// `FixStr` of the same capacity.
+    pub(crate) fn from_fitting(s: &str) -> Self {
+        Self::new(s).unwrap_or_default()
+    }
+
+    /// Collects `chars`, measuring them first so that nothing is built if they do not fit.
+    pub(crate) fn try_from_chars<I>(chars: I) -> Result<Self, CapacityError>
+    where
+        I: Iterator<Item = char> + Clone,
+    {
+        let required = chars.clone().map(char::len_utf8).sum();
+        if required > N {
+            return Err(CapacityError {
+                len: required,
+                capacity: N,
+            });
+        }
+        let mut collected = Self::default();
+        for c in chars {
+            collected.try_push(c)?;
+        }
+        Ok(collected)
+    }
+
+    /// Appends `s`, leaving the string unchanged if it would not fit.
+    pub(crate) fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let start = self.len();
+        let end = start.saturating_add(s.len());
+        let error = CapacityError {
+            len: end,
+            capacity: N,
+        };
+        let len = u8::try_from(end).map_err(|_| error)?;
+        // Slicing the tail to `s.len()` lets the optimizer see that the copy cannot panic
+        let target = self
+            .inline
+            .get_mut(start..)
+            .and_then(|tail| tail.get_mut(..s.len()))
+            .ok_or(error)?;
+        target.copy_from_slice(s.as_bytes());
+        self.len = len;
+        Ok(())
+    }
+
+    /// Appends `c`, leaving the string unchanged if it would not fit.
+    pub(crate) fn try_push(&mut self, c: char) -> Result<(), CapacityError> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
     /// Creates a new `FixStr` without capacity checking.
     ///
+    /// Not available with the `panic-free` feature.
+    ///
     /// # Panics
     /// Panics if the string is too long for the fixed capacity.
+    #[cfg(not(feature = "panic-free"))]
     #[must_use]
     pub fn new_unchecked(s: &str) -> Self {
         Self::new(s)
@@ -84,9 +327,18 @@ impl<const N: usize> FixStr<N> {
     /// # Safety
     /// Safe because we only store valid UTF-8 strings.
     #[must_use]
-    pub fn as_str(&self) -> &str {
-        // SAFETY: We only store valid UTF-8 strings
-        unsafe { std::str::from_utf8_unchecked(&self.inline[..self.len as usize]) }
+    pub const fn as_str(&self) -> &str {
+        utf8::from_utf8_stored(self.as_bytes())
+    }
+
+    /// Returns the string as a slice of octets.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        // `len` never exceeds N, avoiding the slice index keeps this free of panic paths
+        match self.inline.split_at_checked(self.len as usize) {
+            Some((used, _)) => used,
+            None => &[],
+        }
     }
 
     /// Returns the length of the string in Unicode characters.
@@ -94,28 +346,53 @@ impl<const N: usize> FixStr<N> {
     /// This may be different from the octet length for non-ASCII strings.
     #[must_use]
     pub fn char_len(&self) -> usize {
-        self.as_str().chars().count()
+        // Every char starts with exactly one non-continuation octet (not 0b10xx_xxxx)
+        self.as_bytes()
+            .iter()
+            .filter(|&&b| (b & 0xC0) != 0x80)
+            .count()
     }
 
     /// Returns the length of the string in octets.
     #[must_use]
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         self.len as usize
     }
 
     /// Returns true if the string is empty.
     #[must_use]
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
     /// Returns the total capacity in octets.
     #[must_use]
-    pub fn capacity(&self) -> usize {
+    pub const fn capacity(&self) -> usize {
         N
     }
 }
 
+/// Error returned when a string does not fit in the capacity of a `FixStr`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError {
+    /// Length of the rejected string in octets.
+    pub len: usize,
+    /// Capacity of the target `FixStr` in octets.
+    pub capacity: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "String (len={}) exceeds capacity {}",
+            self.len, self.capacity
+        )
+    }
+}
+
+impl std::error::Error for CapacityError {}
+
 impl<const N: usize> TryFrom<&str> for FixStr<N> {
     type Error = String;
 
@@ -0,0 +1,117 @@
+use std::fmt;
+use std::iter::{Chain, Take};
+use std::slice;
+
+use crate::FixStr;
+
+/// A list of up to `K` strings of capacity `N`, stored inline as a ring buffer.
+///
+/// Pushing onto a full list evicts the oldest string, which makes it a natural fit for
+/// bounded logs and chat histories.
+#[derive(Clone, Copy)]
+pub struct FixStrList<const N: usize, const K: usize> {
+    items: [FixStr<N>; K],
+    start: usize,
+    len: usize,
+}
+
+impl<const N: usize, const K: usize> FixStrList<N, K> {
+    /// Creates an empty list.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            items: [FixStr::default(); K],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Appends `s` as the newest string, returning the oldest string if it had to be evicted.
+    pub fn push(&mut self, s: FixStr<N>) -> Option<FixStr<N>> {
+        if self.len < K {
+            let slot = self.items.get_mut(self.len)?;
+            *slot = s;
+            self.len += 1;
+            return None;
+        }
+        // A zero-capacity list hands `s` straight back as evicted
+        let evicted = match self.items.get_mut(self.start) {
+            Some(oldest) => std::mem::replace(oldest, s),
+            None => s,
+        };
+        self.start = (self.start + 1) % K.max(1);
+        Some(evicted)
+    }
+
+    /// Returns an iterator over the strings from oldest to newest.
+    pub fn iter(&self) -> Take<Chain<slice::Iter<'_, FixStr<N>>, slice::Iter<'_, FixStr<N>>>> {
+        let (wrapped, oldest) = self.items.split_at(self.start.min(K));
+        oldest.iter().chain(wrapped).take(self.len)
+    }
+
+    /// Returns the most recently pushed string.
+    #[must_use]
+    pub fn newest(&self) -> Option<&FixStr<N>> {
+        self.iter().last()
+    }
+
+    /// Removes all strings.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the number of strings in the list.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the next push will evict the oldest string.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len == K
+    }
+
+    /// Returns the maximum number of strings the list can hold.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        K
+    }
+}
+
+impl<const N: usize, const K: usize> Default for FixStrList<N, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const K: usize> fmt::Debug for FixStrList<N, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(FixStr::as_str))
+            .finish()
+    }
+}
+
+impl<const N: usize, const K: usize> PartialEq for FixStrList<N, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<const N: usize, const K: usize> Eq for FixStrList<N, K> {}
+
+impl<'a, const N: usize, const K: usize> IntoIterator for &'a FixStrList<N, K> {
+    type Item = &'a FixStr<N>;
+    type IntoIter = Take<Chain<slice::Iter<'a, FixStr<N>>, slice::Iter<'a, FixStr<N>>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,58 @@
+const fn is_country_code(s: &str) -> bool {
+    matches!(s.as_bytes(), [a, b] if a.is_ascii_uppercase() && b.is_ascii_uppercase())
+}
+
+const fn is_lang_tag(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut subtag_len = 0;
+    let mut is_language = true;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'-' {
+            if subtag_len == 0 || (is_language && subtag_len < 2) {
+                return false;
+            }
+            subtag_len = 0;
+            is_language = false;
+        } else if b.is_ascii_alphabetic() || (!is_language && b.is_ascii_digit()) {
+            subtag_len += 1;
+            if subtag_len > 8 {
+                return false;
+            }
+        } else {
+            return false;
+        }
+        i += 1;
+    }
+    subtag_len > 0 && !(is_language && subtag_len < 2)
+}
+
+crate::define_fixstr!(
+    /// An ISO 3166-1 alpha-2 country code such as `"SE"`, stored as two uppercase ASCII letters.
+    ///
+    /// Usable in const context: `const SE: CountryCode = CountryCode::new("SE").unwrap();`
+    pub CountryCode, 2, const is_country_code
+);
+
+crate::define_fixstr!(
+    /// A BCP 47 language tag such as `"en"`, `"pt-BR"` or `"zh-Hant-TW"`.
+    ///
+    /// The tag starts with a language subtag of 2 to 8 ASCII letters, followed by any number of
+    /// hyphen-separated subtags of 1 to 8 ASCII letters or digits. Case is kept as given.
+    ///
+    /// Usable in const context: `const EN: LangTag<8> = LangTag::new("en").unwrap();`
+    pub LangTag<N>, N, const is_lang_tag
+);
+
+impl<const N: usize> LangTag<N> {
+    /// Returns the primary language subtag, such as `"pt"` for `"pt-BR"`.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        let s = self.0.as_str();
+        s.bytes()
+            .position(|b| b == b'-')
+            .and_then(|hyphen| s.split_at_checked(hyphen))
+            .map_or(s, |(language, _)| language)
+    }
+}
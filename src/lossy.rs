@@ -0,0 +1,60 @@
+use crate::FixStr;
+
+/// Data-quality counters reported by [`FixStr::from_utf8_lossy_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LossyStats {
+    /// Input octets that were invalid UTF-8 and replaced with U+FFFD.
+    pub replaced: usize,
+    /// Input octets left out because they did not fit in the capacity.
+    pub dropped: usize,
+}
+
+impl LossyStats {
+    /// Returns true if the input was stored unchanged.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.replaced == 0 && self.dropped == 0
+    }
+}
+
+impl<const N: usize> FixStr<N> {
+    /// Creates a `FixStr` from possibly invalid UTF-8, replacing invalid sequences with
+    /// U+FFFD and cutting off what does not fit, and reports how many input octets were
+    /// affected.
+    ///
+    /// ```
+    /// use fixstr::FixStr;
+    ///
+    /// let (s, stats) = FixStr::<8>::from_utf8_lossy_stats(b"ab\xFFcdefghij");
+    /// assert_eq!(s.as_str(), "ab\u{FFFD}cde");
+    /// assert_eq!((stats.replaced, stats.dropped), (1, 5));
+    /// ```
+    #[must_use]
+    pub fn from_utf8_lossy_stats(bytes: &[u8]) -> (Self, LossyStats) {
+        let mut s = Self::default();
+        let mut stats = LossyStats::default();
+        let mut consumed = 0;
+        for chunk in bytes.utf8_chunks() {
+            let valid = chunk.valid();
+            let room = N.min(u8::MAX as usize) - s.len();
+            let mut end = room.min(valid.len());
+            while !valid.is_char_boundary(end) {
+                end -= 1;
+            }
+            let _ = s.try_push_str(valid.get(..end).unwrap_or_default());
+            consumed += end;
+            if end < valid.len() {
+                break;
+            }
+            if !chunk.invalid().is_empty() {
+                if s.try_push(char::REPLACEMENT_CHARACTER).is_err() {
+                    break;
+                }
+                stats.replaced += chunk.invalid().len();
+                consumed += chunk.invalid().len();
+            }
+        }
+        stats.dropped = bytes.len() - consumed;
+        (s, stats)
+    }
+}
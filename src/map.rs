@@ -0,0 +1,159 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::slice;
+
+use crate::FixStr;
+
+/// A map of up to `K` entries keyed by `FixStr<N>`, stored inline.
+///
+/// Like [`FixStrSet`](crate::FixStrSet), lookups are linear scans, suited to the few entries of
+/// per-message metadata where a `HashMap` allocation would dominate.
+#[derive(Clone, Copy)]
+pub struct FixStrMap<const N: usize, V, const K: usize> {
+    entries: [Option<(FixStr<N>, V)>; K],
+    len: usize,
+}
+
+impl<const N: usize, V, const K: usize> FixStrMap<N, V, K> {
+    /// Creates an empty map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: [const { None }; K],
+            len: 0,
+        }
+    }
+
+    /// Returns the value for `key`.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.iter().find(|(k, _)| k.as_str() == key).map(|(_, v)| v)
+    }
+
+    /// Returns a mutable reference to the value for `key`.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.entries
+            .iter_mut()
+            .flatten()
+            .find(|(k, _)| k.as_str() == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Returns true if the map has a value for `key`.
+    #[must_use]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Sets the value for `key`, returning the previous value if there was one.
+    ///
+    /// # Errors
+    /// Returns the entry back if `key` is new and the map already holds `K` entries.
+    pub fn insert(&mut self, key: FixStr<N>, value: V) -> Result<Option<V>, (FixStr<N>, V)> {
+        if let Some(existing) = self.get_mut(key.as_str()) {
+            return Ok(Some(std::mem::replace(existing, value)));
+        }
+        match self.entries.get_mut(self.len) {
+            Some(slot) => {
+                *slot = Some((key, value));
+                self.len += 1;
+                Ok(None)
+            }
+            None => Err((key, value)),
+        }
+    }
+
+    /// Removes the entry for `key`, returning its value if it was present.
+    ///
+    /// The last entry takes the place of the removed one.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let index = self.position(key)?;
+        self.len -= 1;
+        self.entries.swap(index, self.len);
+        self.entries
+            .get_mut(self.len)
+            .and_then(Option::take)
+            .map(|(_, v)| v)
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|entry| *entry = None);
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the entries.
+    pub fn iter(&self) -> MapIter<'_, N, V> {
+        MapIter {
+            entries: self.entries.iter(),
+        }
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of entries the map can hold.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        K
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.as_ref().is_some_and(|(k, _)| k.as_str() == key))
+    }
+}
+
+/// Iterator returned by [`FixStrMap::iter`].
+#[derive(Clone, Debug)]
+pub struct MapIter<'a, const N: usize, V> {
+    entries: slice::Iter<'a, Option<(FixStr<N>, V)>>,
+}
+
+impl<'a, const N: usize, V> Iterator for MapIter<'a, N, V> {
+    type Item = (&'a FixStr<N>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries
+            .find_map(|entry| entry.as_ref().map(|(k, v)| (k, v)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.entries.size_hint().1)
+    }
+}
+
+impl<const N: usize, V> FusedIterator for MapIter<'_, N, V> {}
+
+impl<const N: usize, V, const K: usize> Default for FixStrMap<N, V, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, V: fmt::Debug, const K: usize> fmt::Debug for FixStrMap<N, V, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(k, v)| (k.as_str(), v)))
+            .finish()
+    }
+}
+
+/// Two maps are equal when they hold the same entries, regardless of order.
+impl<const N: usize, V: PartialEq, const K: usize> PartialEq for FixStrMap<N, V, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|(k, v)| other.get(k.as_str()) == Some(v))
+    }
+}
+
+impl<const N: usize, V: Eq, const K: usize> Eq for FixStrMap<N, V, K> {}
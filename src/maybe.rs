@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::{CapacityError, FixStr};
+
+/// A string that either borrows from its source or owns a short synthesized value inline,
+/// like `Cow<'a, str>` without the heap.
+///
+/// Suited to parsers that usually return slices of their input but sometimes need to build
+/// a new string, such as when unescaping.
+#[derive(Clone, Copy)]
+pub enum MaybeFixStr<'a, const N: usize> {
+    /// A string borrowed from the source.
+    Borrowed(&'a str),
+    /// A string owned inline.
+    Owned(FixStr<N>),
+}
+
+impl<const N: usize> MaybeFixStr<'_, N> {
+    /// Returns a string slice containing the entire string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Borrowed(s) => s,
+            Self::Owned(s) => s.as_str(),
+        }
+    }
+
+    /// Returns true if the string is borrowed from the source.
+    #[must_use]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Returns the string as an owned `FixStr`, copying a borrowed string.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if a borrowed string does not fit in `N` octets.
+    pub fn into_owned(self) -> Result<FixStr<N>, CapacityError> {
+        match self {
+            Self::Borrowed(s) => FixStr::try_new(s),
+            Self::Owned(s) => Ok(s),
+        }
+    }
+}
+
+impl<const N: usize> Deref for MaybeFixStr<'_, N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for MaybeFixStr<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MaybeFixStr(\"{}\")", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for MaybeFixStr<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Compares the strings, regardless of whether they are borrowed or owned.
+impl<const N: usize> PartialEq for MaybeFixStr<'_, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for MaybeFixStr<'_, N> {}
+
+impl<const N: usize> Ord for MaybeFixStr<'_, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> PartialOrd for MaybeFixStr<'_, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Hash for MaybeFixStr<'_, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> AsRef<str> for MaybeFixStr<'_, N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a, const N: usize> From<&'a str> for MaybeFixStr<'a, N> {
+    fn from(s: &'a str) -> Self {
+        Self::Borrowed(s)
+    }
+}
+
+impl<const N: usize> From<FixStr<N>> for MaybeFixStr<'_, N> {
+    fn from(s: FixStr<N>) -> Self {
+        Self::Owned(s)
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+use std::ops::Deref;
+
+use crate::FixStr;
+
+/// A `FixStr` that is guaranteed to hold at least one octet.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyFixStr<const N: usize>(FixStr<N>);
+
+impl<const N: usize> NonEmptyFixStr<N> {
+    /// Creates a new `NonEmptyFixStr` if the input is not empty and fits within capacity.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        FixStr::new(s).and_then(Self::from_fixstr)
+    }
+
+    /// Wraps `s` if it is not empty.
+    #[must_use]
+    pub fn from_fixstr(s: FixStr<N>) -> Option<Self> {
+        (!s.is_empty()).then_some(Self(s))
+    }
+
+    /// Returns the wrapped `FixStr`.
+    #[must_use]
+    pub const fn into_inner(self) -> FixStr<N> {
+        self.0
+    }
+}
+
+impl<const N: usize> fmt::Debug for NonEmptyFixStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NonEmptyFixStr(\"{}\")", self.0.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for NonEmptyFixStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> Deref for NonEmptyFixStr<N> {
+    type Target = FixStr<N>;
+
+    fn deref(&self) -> &FixStr<N> {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsRef<str> for NonEmptyFixStr<N> {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<const N: usize> From<NonEmptyFixStr<N>> for FixStr<N> {
+    fn from(s: NonEmptyFixStr<N>) -> Self {
+        s.0
+    }
+}
+
+impl<const N: usize> TryFrom<FixStr<N>> for NonEmptyFixStr<N> {
+    type Error = String;
+
+    fn try_from(s: FixStr<N>) -> Result<Self, Self::Error> {
+        Self::from_fixstr(s).ok_or_else(|| String::from("String is empty"))
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for NonEmptyFixStr<N> {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        FixStr::try_from(s).and_then(Self::try_from)
+    }
+}
+
+impl<const N: usize> TryFrom<String> for NonEmptyFixStr<N> {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
@@ -0,0 +1,37 @@
+use ::unicode_normalization::UnicodeNormalization;
+
+use crate::{CapacityError, FixStr};
+
+impl<const N: usize> FixStr<N> {
+    /// Creates a new `FixStr` holding the NFC (composed) normalization of `s`.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the normalized string does not fit.
+    pub fn new_nfc(s: &str) -> Result<Self, CapacityError> {
+        Self::try_from_chars(s.nfc())
+    }
+
+    /// Creates a new `FixStr` holding the NFD (decomposed) normalization of `s`.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the normalized string does not fit.
+    pub fn new_nfd(s: &str) -> Result<Self, CapacityError> {
+        Self::try_from_chars(s.nfd())
+    }
+
+    /// Returns the NFC (composed) normalization of the string.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the normalized string does not fit.
+    pub fn normalize_nfc(&self) -> Result<Self, CapacityError> {
+        Self::new_nfc(self.as_str())
+    }
+
+    /// Returns the NFD (decomposed) normalization of the string.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the normalized string does not fit.
+    pub fn normalize_nfd(&self) -> Result<Self, CapacityError> {
+        Self::new_nfd(self.as_str())
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+
+use crate::{CapacityError, FixStr};
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+/// Prefix marking a punycode-encoded label (an "A-label").
+const ACE_PREFIX: &str = "xn--";
+
+/// Error returned by [`FixStr::from_punycode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PunycodeError {
+    /// The decoded hostname does not fit.
+    Capacity(CapacityError),
+    /// The `xn--` label starting at this octet offset is not valid punycode.
+    InvalidLabel {
+        /// Octet offset of the label in the input.
+        index: usize,
+    },
+}
+
+impl fmt::Display for PunycodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(err) => fmt::Display::fmt(err, f),
+            Self::InvalidLabel { index } => {
+                write!(f, "Invalid punycode label at index {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PunycodeError {}
+
+impl From<CapacityError> for PunycodeError {
+    fn from(err: CapacityError) -> Self {
+        Self::Capacity(err)
+    }
+}
+
+/// Bias adaptation from RFC 3492 section 6.1.
+fn adapt(delta: u32, points: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / DAMP } else { delta / 2 };
+    // `points` and the divisor below are never zero; `checked_div` keeps that provable
+    delta += delta.checked_div(points).unwrap_or(0);
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + ((BASE - T_MIN + 1) * delta)
+        .checked_div(delta + SKEW)
+        .unwrap_or(0)
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    k.saturating_sub(bias).clamp(T_MIN, T_MAX)
+}
+
+fn encode_digit(d: u32) -> char {
+    match d {
+        0..=25 => char::from(b'a' + d as u8),
+        _ => char::from(b'0' + (d - 26) as u8),
+    }
+}
+
+/// Splitting with a predicate skips the char searcher, whose memchr slicing keeps panic paths.
+fn is_label_separator(c: char) -> bool {
+    c == '.'
+}
+
+fn decode_digit(b: u8) -> Option<u32> {
+    match b {
+        b'a'..=b'z' => Some(u32::from(b - b'a')),
+        b'A'..=b'Z' => Some(u32::from(b - b'A')),
+        b'0'..=b'9' => Some(u32::from(b - b'0') + 26),
+        _ => None,
+    }
+}
+
+/// Appends the punycode encoding of `label` (without the `xn--` prefix) to `out`.
+fn encode_label<const M: usize>(label: &str, out: &mut FixStr<M>) -> Result<(), CapacityError> {
+    let mut basic = 0;
+    for c in label.chars().filter(char::is_ascii) {
+        out.try_push(c)?;
+        basic += 1;
+    }
+    if basic > 0 {
+        out.try_push('-')?;
+    }
+    // Every char starts with exactly one non-continuation octet (not 0b10xx_xxxx)
+    let total = label.bytes().filter(|&b| (b & 0xC0) != 0x80).count() as u32;
+    let mut handled = basic;
+    let (mut n, mut delta, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    while handled < total {
+        let m = label
+            .chars()
+            .map(u32::from)
+            .filter(|&c| c >= n)
+            .min()
+            .unwrap_or(n);
+        delta += (m - n) * (handled + 1);
+        n = m;
+        for c in label.chars().map(u32::from) {
+            if c < n {
+                delta += 1;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    out.try_push(encode_digit(t + (q - t) % (BASE - t)))?;
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                out.try_push(encode_digit(q))?;
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Ok(())
+}
+
+/// Decodes `encoded` (without the `xn--` prefix) into `out`, returning the number of chars.
+fn decode_label(encoded: &str, out: &mut [char; 255]) -> Option<usize> {
+    let (basic, digits) = match encoded.bytes().rposition(|b| b == b'-') {
+        Some(end) => (encoded.get(..end)?, encoded.get(end + 1..)?),
+        None => ("", encoded),
+    };
+    let mut len = 0;
+    for c in basic.chars() {
+        if !c.is_ascii() {
+            return None;
+        }
+        *out.get_mut(len)? = c;
+        len += 1;
+    }
+    let (mut n, mut i, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    let mut digits = digits.bytes();
+    while digits.len() > 0 {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let digit = decode_digit(digits.next()?)?;
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+        let points = len as u32 + 1;
+        bias = adapt(i - old_i, points, old_i == 0);
+        n = n.checked_add(i.checked_div(points)?)?;
+        i = i.checked_rem(points)?;
+        let c = char::from_u32(n)?;
+        // Insert at `i`, shifting the chars after it one step right
+        let mut carried = c;
+        for slot in out.get_mut(i as usize..=len)? {
+            carried = std::mem::replace(slot, carried);
+        }
+        len += 1;
+        i += 1;
+    }
+    Some(len)
+}
+
+impl<const N: usize> FixStr<N> {
+    /// Converts an internationalized hostname to its ASCII form, encoding every label that
+    /// contains non-ASCII chars as `xn--` followed by its punycode (RFC 3492).
+    ///
+    /// ```
+    /// use fixstr::FixStr;
+    ///
+    /// let host: FixStr<32> = FixStr::new("bücher.example").unwrap();
+    /// assert_eq!(host.to_punycode::<32>().unwrap().as_str(), "xn--bcher-kva.example");
+    /// ```
+    ///
+    /// Labels are encoded as given: the IDNA mapping step (lowercasing and normalization)
+    /// is not applied, so pass names that are already in their canonical form.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the encoded hostname does not fit in `M` octets.
+    pub fn to_punycode<const M: usize>(&self) -> Result<FixStr<M>, CapacityError> {
+        let mut out = FixStr::default();
+        for (i, label) in self.as_str().split(is_label_separator).enumerate() {
+            if i > 0 {
+                out.try_push('.')?;
+            }
+            if label.is_ascii() {
+                out.try_push_str(label)?;
+            } else {
+                out.try_push_str(ACE_PREFIX)?;
+                encode_label(label, &mut out)?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Converts a hostname in ASCII form back to Unicode, decoding every label that starts
+    /// with `xn--`.
+    ///
+    /// # Errors
+    /// Returns `PunycodeError::InvalidLabel` for the first label that is not valid punycode
+    /// and `PunycodeError::Capacity` if the decoded hostname does not fit.
+    pub fn from_punycode(s: &str) -> Result<Self, PunycodeError> {
+        let mut out = Self::default();
+        let mut index = 0;
+        let mut chars = ['\0'; 255];
+        for (i, label) in s.split(is_label_separator).enumerate() {
+            if i > 0 {
+                out.try_push('.')?;
+            }
+            let encoded = label
+                .get(..ACE_PREFIX.len())
+                .filter(|prefix| prefix.eq_ignore_ascii_case(ACE_PREFIX))
+                .and_then(|_| label.get(ACE_PREFIX.len()..));
+            match encoded {
+                Some(encoded) => {
+                    let len = decode_label(encoded, &mut chars)
+                        .ok_or(PunycodeError::InvalidLabel { index })?;
+                    for &c in chars.get(..len).unwrap_or_default() {
+                        out.try_push(c)?;
+                    }
+                }
+                None => out.try_push_str(label)?,
+            }
+            index += label.len() + 1;
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,540 @@
+use crate::FixStr;
+use std::str::{Chars, Lines, SplitWhitespace};
+
+/// A needle that can be searched for in a `FixStr`.
+///
+/// Implemented for `char`, `&str` and `&FixStr`. Single octet needles (ASCII chars and one
+/// octet strings) are found with a byte scan instead of decoding chars.
+///
+/// `str::find` only accepts the standard library's unstable `Pattern` trait, so plain
+/// haystacks are searched from the needle side instead:
+///
+/// ```
+/// use fixstr::{FixStr, Needle};
+///
+/// let name: FixStr<8> = FixStr::new("volume").unwrap();
+/// assert_eq!((&name).find_in("cfg.audio.volume"), Some(10));
+/// ```
+pub trait Needle {
+    /// Returns the octet index of the first match in `haystack`.
+    fn find_in(&self, haystack: &str) -> Option<usize>;
+
+    /// Returns the octet index of the last match in `haystack`.
+    fn rfind_in(&self, haystack: &str) -> Option<usize>;
+
+    /// Returns the octet length of the match if `haystack` starts with the needle.
+    fn prefix_len_in(&self, haystack: &str) -> Option<usize>;
+
+    /// Returns the octet length of the match if `haystack` ends with the needle.
+    fn suffix_len_in(&self, haystack: &str) -> Option<usize>;
+}
+
+impl Needle for char {
+    fn find_in(&self, haystack: &str) -> Option<usize> {
+        if self.is_ascii() {
+            find_byte(haystack.as_bytes(), *self as u8)
+        } else {
+            find_str(haystack, self.encode_utf8(&mut [0; 4]))
+        }
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<usize> {
+        if self.is_ascii() {
+            rfind_byte(haystack.as_bytes(), *self as u8)
+        } else {
+            rfind_str(haystack, self.encode_utf8(&mut [0; 4]))
+        }
+    }
+
+    fn prefix_len_in(&self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then(|| self.len_utf8())
+    }
+
+    fn suffix_len_in(&self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then(|| self.len_utf8())
+    }
+}
+
+impl Needle for &str {
+    fn find_in(&self, haystack: &str) -> Option<usize> {
+        match self.as_bytes() {
+            [byte] => find_byte(haystack.as_bytes(), *byte),
+            _ => find_str(haystack, self),
+        }
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<usize> {
+        match self.as_bytes() {
+            [byte] => rfind_byte(haystack.as_bytes(), *byte),
+            _ => rfind_str(haystack, self),
+        }
+    }
+
+    fn prefix_len_in(&self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then_some(self.len())
+    }
+
+    fn suffix_len_in(&self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then_some(self.len())
+    }
+}
+
+impl<const M: usize> Needle for &FixStr<M> {
+    fn find_in(&self, haystack: &str) -> Option<usize> {
+        self.as_str().find_in(haystack)
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<usize> {
+        self.as_str().rfind_in(haystack)
+    }
+
+    fn prefix_len_in(&self, haystack: &str) -> Option<usize> {
+        self.as_str().prefix_len_in(haystack)
+    }
+
+    fn suffix_len_in(&self, haystack: &str) -> Option<usize> {
+        self.as_str().suffix_len_in(haystack)
+    }
+}
+
+#[cfg(all(feature = "memchr", not(feature = "panic-free")))]
+fn find_byte(haystack: &[u8], byte: u8) -> Option<usize> {
+    memchr::memchr(byte, haystack)
+}
+
+#[cfg(any(not(feature = "memchr"), feature = "panic-free"))]
+fn find_byte(haystack: &[u8], byte: u8) -> Option<usize> {
+    haystack.iter().position(|&b| b == byte)
+}
+
+#[cfg(all(feature = "memchr", not(feature = "panic-free")))]
+fn rfind_byte(haystack: &[u8], byte: u8) -> Option<usize> {
+    memchr::memrchr(byte, haystack)
+}
+
+#[cfg(any(not(feature = "memchr"), feature = "panic-free"))]
+fn rfind_byte(haystack: &[u8], byte: u8) -> Option<usize> {
+    haystack.iter().rposition(|&b| b == byte)
+}
+
+#[cfg(all(feature = "memchr", not(feature = "panic-free")))]
+fn find_str(haystack: &str, needle: &str) -> Option<usize> {
+    memchr::memmem::find(haystack.as_bytes(), needle.as_bytes())
+}
+
+/// Compares at every offset instead of using `str::find`, whose two-way searcher keeps
+/// panic paths; with at most 255 octets of haystack the naive scan is cheap.
+#[cfg(any(not(feature = "memchr"), feature = "panic-free"))]
+fn find_str(haystack: &str, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window == needle.as_bytes())
+}
+
+#[cfg(all(feature = "memchr", not(feature = "panic-free")))]
+fn rfind_str(haystack: &str, needle: &str) -> Option<usize> {
+    memchr::memmem::rfind(haystack.as_bytes(), needle.as_bytes())
+}
+
+#[cfg(any(not(feature = "memchr"), feature = "panic-free"))]
+fn rfind_str(haystack: &str, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(haystack.len());
+    }
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .rposition(|window| window == needle.as_bytes())
+}
+
+impl<const N: usize> FixStr<N> {
+    /// Returns the octet index of the first occurrence of `needle`.
+    #[must_use]
+    pub fn find<P: Needle>(&self, needle: P) -> Option<usize> {
+        needle.find_in(self.as_str())
+    }
+
+    /// Returns the octet index of the last occurrence of `needle`.
+    #[must_use]
+    pub fn rfind<P: Needle>(&self, needle: P) -> Option<usize> {
+        needle.rfind_in(self.as_str())
+    }
+
+    /// Returns true if `needle` occurs in the string.
+    #[must_use]
+    pub fn contains<P: Needle>(&self, needle: P) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Returns the index of the first octet equal to `byte`.
+    ///
+    /// Scans octets directly, which avoids decoding chars when the delimiter is known
+    /// to be ASCII (such as `:`, `/` or `\0`).
+    #[must_use]
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        find_byte(self.as_bytes(), byte)
+    }
+
+    /// Returns the index of the last octet equal to `byte`.
+    #[must_use]
+    pub fn rfind_byte(&self, byte: u8) -> Option<usize> {
+        rfind_byte(self.as_bytes(), byte)
+    }
+
+    /// Splits the string on the first occurrence of the ASCII `byte`, excluding it.
+    ///
+    /// Returns `None` if `byte` is not found or is not ASCII, since splitting on a
+    /// non-ASCII octet would cut a char in half.
+    #[must_use]
+    pub fn split_once_byte(&self, byte: u8) -> Option<(&str, &str)> {
+        if !byte.is_ascii() {
+            return None;
+        }
+        let index = self.find_byte(byte)?;
+        let (before, rest) = self.as_str().split_at_checked(index)?;
+        Some((before, rest.get(1..)?))
+    }
+
+    /// Returns true if the string starts with `prefix`.
+    #[must_use]
+    pub fn starts_with<P: Needle>(&self, prefix: P) -> bool {
+        prefix.prefix_len_in(self.as_str()).is_some()
+    }
+
+    /// Returns true if the string ends with `suffix`.
+    #[must_use]
+    pub fn ends_with<P: Needle>(&self, suffix: P) -> bool {
+        suffix.suffix_len_in(self.as_str()).is_some()
+    }
+
+    /// Returns the string with `prefix` removed, or `None` if it does not start with it.
+    #[must_use]
+    pub fn strip_prefix<P: Needle>(&self, prefix: P) -> Option<Self> {
+        let s = self.as_str();
+        let len = prefix.prefix_len_in(s)?;
+        s.get(len..).map(Self::from_fitting)
+    }
+
+    /// Returns the string with `suffix` removed, or `None` if it does not end with it.
+    #[must_use]
+    pub fn strip_suffix<P: Needle>(&self, suffix: P) -> Option<Self> {
+        let s = self.as_str();
+        let len = suffix.suffix_len_in(s)?;
+        s.get(..s.len().saturating_sub(len)).map(Self::from_fitting)
+    }
+
+    /// Splits the string by `needle`, yielding each segment as a `FixStr<N>`.
+    ///
+    /// Every segment is a part of this string, so it always fits. The segments are
+    /// owned, so they can be stored after this string is gone.
+    pub fn split_fix<P: Needle>(&self, needle: P) -> SplitFix<'_, P, N> {
+        SplitFix {
+            inner: SplitStr::new(self.as_str(), needle),
+        }
+    }
+
+    /// Splits the string by `needle` into exactly `K` segments.
+    ///
+    /// Returns `None` if the string does not split into exactly `K` segments, which makes
+    /// it convenient for destructuring fixed field layouts such as `"x:y:z"`.
+    #[must_use]
+    pub fn split_array<const K: usize, P: Needle>(&self, needle: P) -> Option<[&str; K]> {
+        let mut segments = SplitStr::new(self.as_str(), needle);
+        let mut out = [""; K];
+        for slot in &mut out {
+            *slot = segments.next()?;
+        }
+        segments.next().is_none().then_some(out)
+    }
+
+    /// Splits the string by whitespace, like `str::split_whitespace`.
+    pub fn split_whitespace(&self) -> SplitWhitespace<'_> {
+        self.as_str().split_whitespace()
+    }
+
+    /// Splits the string by whitespace, yielding each word as a `FixStr<N>`.
+    pub fn split_whitespace_fix(&self) -> SplitWhitespaceFix<'_, N> {
+        SplitWhitespaceFix {
+            inner: self.split_whitespace(),
+        }
+    }
+
+    /// Iterates over the matches of `needle`, like `str::matches`.
+    pub fn matches<P: Needle>(&self, needle: P) -> Matches<'_, P> {
+        Matches {
+            inner: self.match_indices(needle),
+        }
+    }
+
+    /// Iterates over the matches of `needle` and their octet indices, like
+    /// `str::match_indices`.
+    pub fn match_indices<P: Needle>(&self, needle: P) -> MatchIndices<'_, P> {
+        MatchIndices {
+            haystack: self.as_str(),
+            position: 0,
+            needle,
+            done: false,
+        }
+    }
+
+    /// Returns the number of non-overlapping occurrences of `needle`.
+    #[must_use]
+    pub fn count_matches<P: Needle>(&self, needle: P) -> usize {
+        self.match_indices(needle).count()
+    }
+
+    /// Returns true if the whole string matches the wildcard `pattern`.
+    ///
+    /// `*` matches any sequence of chars (including none) and `?` matches exactly one
+    /// char. All other chars match themselves.
+    #[must_use]
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        let mut text = self.as_str().chars();
+        let mut pattern = pattern.chars();
+        // Pattern after the last `*` and the text position that `*` currently extends to
+        let mut star: Option<(Chars<'_>, Chars<'_>)> = None;
+
+        loop {
+            match pattern.next() {
+                Some('*') => {
+                    star = Some((pattern.clone(), text.clone()));
+                    continue;
+                }
+                Some(expected) => {
+                    if let Some(c) = text.next() {
+                        if expected == '?' || expected == c {
+                            continue;
+                        }
+                    }
+                }
+                None => {
+                    if text.as_str().is_empty() {
+                        return true;
+                    }
+                }
+            }
+
+            // Mismatch: let the last `*` swallow one more char and retry from there
+            let Some((after_star, star_text)) = &mut star else {
+                return false;
+            };
+            if star_text.next().is_none() {
+                return false;
+            }
+            pattern = after_star.clone();
+            text = star_text.clone();
+        }
+    }
+
+    /// Iterates over the lines of the string, like `str::lines`.
+    pub fn lines(&self) -> Lines<'_> {
+        self.as_str().lines()
+    }
+
+    /// Iterates over the lines of the string, yielding each line as a `FixStr<N>`.
+    pub fn lines_fix(&self) -> LinesFix<'_, N> {
+        LinesFix {
+            rest: self.as_str(),
+        }
+    }
+}
+
+/// Splits a borrowed string by a needle, with the same segments as `str::split`.
+#[derive(Clone, Debug)]
+struct SplitStr<'a, P> {
+    rest: &'a str,
+    needle: P,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, P: Needle> SplitStr<'a, P> {
+    fn new(haystack: &'a str, needle: P) -> Self {
+        Self {
+            rest: haystack,
+            needle,
+            started: false,
+            done: false,
+        }
+    }
+}
+
+impl<'a, P: Needle> Iterator for SplitStr<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let started = std::mem::replace(&mut self.started, true);
+        let Some(index) = self.needle.find_in(self.rest) else {
+            self.done = true;
+            return Some(self.rest);
+        };
+        let (segment, after) = self.rest.split_at_checked(index)?;
+        let match_len = self.needle.prefix_len_in(after).unwrap_or(0);
+        if match_len > 0 {
+            self.rest = after.get(match_len..)?;
+            return Some(segment);
+        }
+
+        // An empty needle matches at every char boundary, like `str::split("")`
+        if !started {
+            return Some("");
+        }
+        let mut chars = self.rest.chars();
+        if chars.next().is_none() {
+            self.done = true;
+            return Some("");
+        }
+        let (first, rest) = self
+            .rest
+            .split_at_checked(self.rest.len() - chars.as_str().len())?;
+        self.rest = rest;
+        Some(first)
+    }
+}
+
+/// Iterator returned by [`FixStr::split_fix`].
+#[derive(Clone, Debug)]
+pub struct SplitFix<'a, P, const N: usize> {
+    inner: SplitStr<'a, P>,
+}
+
+impl<P: Needle, const N: usize> Iterator for SplitFix<'_, P, N> {
+    type Item = FixStr<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(FixStr::from_fitting)
+    }
+}
+
+/// Iterator returned by [`FixStr::split_whitespace_fix`].
+#[derive(Clone, Debug)]
+pub struct SplitWhitespaceFix<'a, const N: usize> {
+    inner: SplitWhitespace<'a>,
+}
+
+impl<const N: usize> Iterator for SplitWhitespaceFix<'_, N> {
+    type Item = FixStr<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(FixStr::from_fitting)
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for SplitWhitespaceFix<'_, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(FixStr::from_fitting)
+    }
+}
+
+/// Iterator returned by [`FixStr::lines_fix`].
+///
+/// Scans for `\n` itself rather than wrapping `str::Lines`, which keeps panic paths.
+#[derive(Clone, Debug)]
+pub struct LinesFix<'a, const N: usize> {
+    rest: &'a str,
+}
+
+/// Removes the `\r` of a `\r\n` line ending.
+fn strip_carriage_return(line: &str) -> &str {
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+impl<const N: usize> Iterator for LinesFix<'_, N> {
+    type Item = FixStr<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let line = match find_byte(self.rest.as_bytes(), b'\n')
+            .and_then(|end| self.rest.split_at_checked(end))
+        {
+            Some((line, newline)) => {
+                self.rest = newline.get(1..).unwrap_or_default();
+                strip_carriage_return(line)
+            }
+            None => std::mem::take(&mut self.rest),
+        };
+        Some(FixStr::from_fitting(line))
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for LinesFix<'_, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (body, terminated) = match self.rest.strip_suffix('\n') {
+            Some(body) => (body, true),
+            None => (self.rest, false),
+        };
+        let (rest, line) = rfind_byte(body.as_bytes(), b'\n')
+            .and_then(|end| body.split_at_checked(end + 1))
+            .unwrap_or(("", body));
+        self.rest = rest;
+        let line = if terminated {
+            strip_carriage_return(line)
+        } else {
+            line
+        };
+        Some(FixStr::from_fitting(line))
+    }
+}
+
+/// Iterator returned by [`FixStr::match_indices`].
+#[derive(Clone, Debug)]
+pub struct MatchIndices<'a, P> {
+    haystack: &'a str,
+    position: usize,
+    needle: P,
+    done: bool,
+}
+
+impl<'a, P: Needle> Iterator for MatchIndices<'a, P> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let rest = self.haystack.get(self.position..)?;
+        let Some(index) = self.needle.find_in(rest) else {
+            self.done = true;
+            return None;
+        };
+        let start = self.position + index;
+        let after = self.haystack.get(start..)?;
+        let match_len = self.needle.prefix_len_in(after).unwrap_or(0);
+        if match_len > 0 {
+            self.position = start + match_len;
+        } else {
+            // An empty needle matches at every char boundary, including the end
+            match after.chars().next() {
+                Some(c) => self.position = start + c.len_utf8(),
+                None => self.done = true,
+            }
+        }
+        Some((start, after.get(..match_len)?))
+    }
+}
+
+/// Iterator returned by [`FixStr::matches`].
+#[derive(Clone, Debug)]
+pub struct Matches<'a, P> {
+    inner: MatchIndices<'a, P>,
+}
+
+impl<'a, P: Needle> Iterator for Matches<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, m)| m)
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+
+use crate::FixStr;
+
+/// A set of up to `K` strings of capacity `N`, stored inline.
+///
+/// Lookups are linear scans, which for the handful of entries this is meant for (such as tags
+/// on an entity) beat hashing while keeping the whole set `Copy`.
+#[derive(Clone, Copy)]
+pub struct FixStrSet<const N: usize, const K: usize> {
+    items: [FixStr<N>; K],
+    len: usize,
+}
+
+impl<const N: usize, const K: usize> FixStrSet<N, K> {
+    /// Creates an empty set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            items: [FixStr::default(); K],
+            len: 0,
+        }
+    }
+
+    /// Returns the strings in the set, in insertion order unless some were removed.
+    #[must_use]
+    pub fn as_slice(&self) -> &[FixStr<N>] {
+        self.items.get(..self.len).unwrap_or_default()
+    }
+
+    /// Returns an iterator over the strings in the set.
+    pub fn iter(&self) -> std::slice::Iter<'_, FixStr<N>> {
+        self.as_slice().iter()
+    }
+
+    /// Returns true if the set contains `s`.
+    #[must_use]
+    pub fn contains(&self, s: &str) -> bool {
+        self.position(s).is_some()
+    }
+
+    /// Adds `s` to the set, returning whether it was newly inserted.
+    ///
+    /// # Errors
+    /// Returns `s` back if it is not in the set and the set already holds `K` strings.
+    pub fn insert(&mut self, s: FixStr<N>) -> Result<bool, FixStr<N>> {
+        if self.contains(s.as_str()) {
+            return Ok(false);
+        }
+        let slot = self.items.get_mut(self.len).ok_or(s)?;
+        *slot = s;
+        self.len += 1;
+        Ok(true)
+    }
+
+    /// Removes `s` from the set, returning whether it was present.
+    ///
+    /// The last string takes the place of the removed one.
+    pub fn remove(&mut self, s: &str) -> bool {
+        let Some(index) = self.position(s) else {
+            return false;
+        };
+        self.len -= 1;
+        let last = self
+            .items
+            .get_mut(self.len)
+            .map(std::mem::take)
+            .unwrap_or_default();
+        if index < self.len {
+            if let Some(removed) = self.items.get_mut(index) {
+                *removed = last;
+            }
+        }
+        true
+    }
+
+    /// Removes all strings.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the number of strings in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the set is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of strings the set can hold.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        K
+    }
+
+    fn position(&self, s: &str) -> Option<usize> {
+        self.iter().position(|item| item.as_str() == s)
+    }
+}
+
+impl<const N: usize, const K: usize> Default for FixStrSet<N, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const K: usize> fmt::Debug for FixStrSet<N, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set()
+            .entries(self.iter().map(FixStr::as_str))
+            .finish()
+    }
+}
+
+/// Two sets are equal when they hold the same strings, regardless of order.
+impl<const N: usize, const K: usize> PartialEq for FixStrSet<N, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|s| other.contains(s.as_str()))
+    }
+}
+
+impl<const N: usize, const K: usize> Eq for FixStrSet<N, K> {}
+
+impl<'a, const N: usize, const K: usize> IntoIterator for &'a FixStrSet<N, K> {
+    type Item = &'a FixStr<N>;
+    type IntoIter = std::slice::Iter<'a, FixStr<N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
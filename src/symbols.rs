@@ -0,0 +1,84 @@
+//! A process-wide symbol table, so names can be compared and hashed as integers.
+//!
+//! ```
+//! use fixstr::symbols::Symbol;
+//!
+//! let jump = Symbol::intern("jump").unwrap();
+//! assert_eq!(jump, Symbol::intern("jump").unwrap());
+//! assert_eq!(jump.resolve().as_str(), "jump");
+//! ```
+
+use std::fmt;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use crate::{interner, FixStr, FixStrInterner, InternError};
+
+/// The longest name, in octets, that can be interned.
+pub const MAX_LEN: usize = 32;
+
+fn table() -> &'static RwLock<FixStrInterner<MAX_LEN>> {
+    static TABLE: OnceLock<RwLock<FixStrInterner<MAX_LEN>>> = OnceLock::new();
+    TABLE.get_or_init(RwLock::default)
+}
+
+/// A handle to a name in the global symbol table.
+///
+/// Symbols are `Copy` and compare and hash as integers; ordering follows interning order,
+/// not the names.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(interner::Symbol);
+
+impl Symbol {
+    /// Returns the symbol for `s`, adding it to the global table if needed.
+    ///
+    /// # Errors
+    /// Returns `InternError::Capacity` if `s` is longer than [`MAX_LEN`] octets and
+    /// `InternError::Full` if the table has run out of symbols.
+    pub fn intern(s: &str) -> Result<Self, InternError> {
+        if let Some(symbol) = Self::get(s) {
+            return Ok(symbol);
+        }
+        let mut table = table().write().unwrap_or_else(PoisonError::into_inner);
+        table.intern_str(s).map(Self)
+    }
+
+    /// Returns the symbol for `s` if it has been interned.
+    #[must_use]
+    pub fn get(s: &str) -> Option<Self> {
+        let table = table().read().unwrap_or_else(PoisonError::into_inner);
+        table.get(s).map(Self)
+    }
+
+    /// Returns the interned name.
+    #[must_use]
+    pub fn resolve(self) -> FixStr<MAX_LEN> {
+        let table = table().read().unwrap_or_else(PoisonError::into_inner);
+        table.resolve(self.0).unwrap_or_default()
+    }
+
+    /// Returns the position of the name in the global table, in interning order.
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0.index()
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Symbol(\"{}\")", self.resolve().as_str())
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.resolve(), f)
+    }
+}
+
+impl TryFrom<&str> for Symbol {
+    type Error = InternError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::intern(s)
+    }
+}
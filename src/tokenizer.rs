@@ -0,0 +1,71 @@
+use crate::{CapacityError, FixStr};
+
+/// Splits a string into `FixStr<N>` tokens separated by runs of delimiters, without allocating.
+///
+/// Overlong tokens are reported as `CapacityError` and skipped, so tokenizing can continue
+/// after them.
+///
+/// ```
+/// use fixstr::Tokenizer;
+///
+/// let mut tokens = Tokenizer::<8>::new("give  sword 2");
+/// assert_eq!(tokens.next().unwrap().unwrap().as_str(), "give");
+/// assert_eq!(tokens.remainder(), "sword 2");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tokenizer<'a, const N: usize> {
+    rest: &'a str,
+    delimiters: Option<&'a [char]>,
+}
+
+impl<'a, const N: usize> Tokenizer<'a, N> {
+    /// Creates a tokenizer that splits on whitespace.
+    #[must_use]
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            rest: input,
+            delimiters: None,
+        }
+    }
+
+    /// Creates a tokenizer that splits on any of `delimiters` instead of whitespace.
+    #[must_use]
+    pub fn with_delimiters(input: &'a str, delimiters: &'a [char]) -> Self {
+        Self {
+            rest: input,
+            delimiters: Some(delimiters),
+        }
+    }
+
+    /// Returns the input that has not been tokenized yet, without leading delimiters.
+    #[must_use]
+    pub fn remainder(&self) -> &'a str {
+        self.rest.trim_start_matches(|c| self.is_delimiter(c))
+    }
+
+    fn is_delimiter(&self, c: char) -> bool {
+        match self.delimiters {
+            Some(delimiters) => delimiters.contains(&c),
+            None => c.is_whitespace(),
+        }
+    }
+}
+
+impl<const N: usize> Iterator for Tokenizer<'_, N> {
+    type Item = Result<FixStr<N>, CapacityError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.remainder();
+        if rest.is_empty() {
+            self.rest = rest;
+            return None;
+        }
+        let (token, rest) = rest
+            .char_indices()
+            .find(|&(_, c)| self.is_delimiter(c))
+            .and_then(|(end, _)| rest.split_at_checked(end))
+            .unwrap_or((rest, ""));
+        self.rest = rest;
+        Some(FixStr::try_new(token))
+    }
+}
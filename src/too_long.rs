@@ -0,0 +1,56 @@
+use std::fmt;
+
+use crate::{CapacityError, FixStr};
+
+/// Error returned when a string does not fit, carrying the part of it that did.
+///
+/// Lets diagnostics show the rejected value without the caller re-slicing it:
+/// `String too long: 'VeryLongPlayerNa…' (23 > 16)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooLongError<const N: usize> {
+    /// The longest prefix of the rejected string that fits, cut at a char boundary.
+    pub prefix: FixStr<N>,
+    /// Length of the rejected string in octets.
+    pub len: usize,
+}
+
+impl<const N: usize> FixStr<N> {
+    /// Creates a new `FixStr`, keeping the part that fits in the error when the input is
+    /// too long.
+    ///
+    /// # Errors
+    /// Returns `TooLongError` if the string is too long (> N octets) or exceeds `u8::MAX`.
+    pub fn try_new_or_prefix(s: &str) -> Result<Self, TooLongError<N>> {
+        Self::new(s).ok_or_else(|| {
+            let mut end = N.min(u8::MAX as usize).min(s.len());
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            TooLongError {
+                prefix: Self::from_fitting(s.get(..end).unwrap_or_default()),
+                len: s.len(),
+            }
+        })
+    }
+}
+
+impl<const N: usize> fmt::Display for TooLongError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "String too long: '{}…' ({} > {N})",
+            self.prefix, self.len
+        )
+    }
+}
+
+impl<const N: usize> std::error::Error for TooLongError<N> {}
+
+impl<const N: usize> From<TooLongError<N>> for CapacityError {
+    fn from(error: TooLongError<N>) -> Self {
+        CapacityError {
+            len: error.len,
+            capacity: N,
+        }
+    }
+}
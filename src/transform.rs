@@ -0,0 +1,164 @@
+use crate::{CapacityError, FixStr, Needle};
+
+impl<const N: usize> FixStr<N> {
+    /// Returns the string with leading and trailing whitespace removed.
+    #[must_use]
+    pub fn trim(&self) -> Self {
+        Self::from_fitting(self.as_str().trim())
+    }
+
+    /// Returns the string with leading whitespace removed.
+    #[must_use]
+    pub fn trim_start(&self) -> Self {
+        Self::from_fitting(self.as_str().trim_start())
+    }
+
+    /// Returns the string with trailing whitespace removed.
+    #[must_use]
+    pub fn trim_end(&self) -> Self {
+        Self::from_fitting(self.as_str().trim_end())
+    }
+
+    /// Returns the string with all leading and trailing repetitions of `needle` removed.
+    #[must_use]
+    pub fn trim_matches<P: Needle>(&self, needle: P) -> Self {
+        let mut s = self.as_str();
+        while let Some(rest) = needle
+            .prefix_len_in(s)
+            .filter(|&len| len > 0)
+            .and_then(|len| s.get(len..))
+        {
+            s = rest;
+        }
+        while let Some(rest) = needle
+            .suffix_len_in(s)
+            .filter(|&len| len > 0)
+            .and_then(|len| s.get(..s.len() - len))
+        {
+            s = rest;
+        }
+        Self::from_fitting(s)
+    }
+
+    /// Removes leading and trailing whitespace in place.
+    pub fn trim_in_place(&mut self) {
+        *self = self.trim();
+    }
+
+    /// Returns the string with its chars in reverse order.
+    #[must_use]
+    pub fn reversed(&self) -> Self {
+        Self::try_from_chars(self.as_str().chars().rev()).unwrap_or_default()
+    }
+
+    /// Returns the string with ANSI CSI escape sequences (such as SGR colors, `ESC [ 1 ; 31 m`)
+    /// removed.
+    ///
+    /// An unterminated sequence at the end is removed as well. Since text is only removed the
+    /// result always fits.
+    #[must_use]
+    pub fn strip_ansi(&self) -> Self {
+        let mut stripped = Self::default();
+        let mut rest = self.as_str();
+        while let Some((text, sequence)) = rest
+            .as_bytes()
+            .windows(2)
+            .position(|pair| pair == b"\x1b[")
+            .and_then(|start| rest.split_at_checked(start))
+        {
+            let _ = stripped.try_push_str(text);
+            // Parameter and intermediate octets are 0x20..=0x3F, the final octet 0x40..=0x7E
+            let end = sequence
+                .bytes()
+                .skip(2)
+                .position(|b| !(0x20..=0x3F).contains(&b))
+                .map_or(sequence.len(), |i| {
+                    let end = i + 2;
+                    match sequence.as_bytes().get(end) {
+                        Some(0x40..=0x7E) => end + 1,
+                        _ => end,
+                    }
+                });
+            rest = sequence.get(end..).unwrap_or_default();
+        }
+        let _ = stripped.try_push_str(rest);
+        stripped
+    }
+
+    /// Returns the string escaped like `str::escape_debug`, e.g. `"a\n"` becomes `a\\n`.
+    ///
+    /// Not available with the `panic-free` feature, as the printable-char tables in `core`
+    /// keep panic paths.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the escaped string does not fit in `M` octets.
+    #[cfg(not(feature = "panic-free"))]
+    pub fn escape_debug<const M: usize>(&self) -> Result<FixStr<M>, CapacityError> {
+        FixStr::try_from_chars(self.as_str().escape_debug())
+    }
+
+    /// Returns the string escaped like `str::escape_default`, which also escapes all
+    /// non-ASCII chars as `\u{...}`.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the escaped string does not fit in `M` octets.
+    pub fn escape_default<const M: usize>(&self) -> Result<FixStr<M>, CapacityError> {
+        FixStr::try_from_chars(self.as_str().escape_default())
+    }
+
+    /// Pads the start with `fill` until the string is `width` chars wide.
+    ///
+    /// Strings that are already at least `width` chars wide are returned unchanged.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the padded string does not fit.
+    pub fn pad_start(&self, width: usize, fill: char) -> Result<Self, CapacityError> {
+        self.pad(width.saturating_sub(self.char_len()), 0, fill)
+    }
+
+    /// Pads the end with `fill` until the string is `width` chars wide.
+    ///
+    /// Strings that are already at least `width` chars wide are returned unchanged.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the padded string does not fit.
+    pub fn pad_end(&self, width: usize, fill: char) -> Result<Self, CapacityError> {
+        self.pad(0, width.saturating_sub(self.char_len()), fill)
+    }
+
+    /// Pads both ends with `fill` until the string is `width` chars wide. When the padding
+    /// cannot be split evenly, the extra char goes to the end.
+    ///
+    /// Strings that are already at least `width` chars wide are returned unchanged.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the padded string does not fit.
+    pub fn center(&self, width: usize, fill: char) -> Result<Self, CapacityError> {
+        let padding = width.saturating_sub(self.char_len());
+        self.pad(padding / 2, padding - padding / 2, fill)
+    }
+
+    fn pad(&self, before: usize, after: usize, fill: char) -> Result<Self, CapacityError> {
+        // Widths near usize::MAX overflow; report them as the largest possible length
+        let required = before
+            .checked_add(after)
+            .and_then(|chars| chars.checked_mul(fill.len_utf8()))
+            .and_then(|octets| octets.checked_add(self.len()))
+            .unwrap_or(usize::MAX);
+        if required > N {
+            return Err(CapacityError {
+                len: required,
+                capacity: N,
+            });
+        }
+        let mut padded = Self::default();
+        for _ in 0..before {
+            padded.try_push(fill)?;
+        }
+        padded.try_push_str(self.as_str())?;
+        for _ in 0..after {
+            padded.try_push(fill)?;
+        }
+        Ok(padded)
+    }
+}
@@ -0,0 +1,32 @@
+use std::str::EncodeUtf16;
+
+use crate::{CapacityError, FixStr};
+
+impl<const N: usize> FixStr<N> {
+    /// Returns an iterator over the string encoded as UTF-16 code units.
+    pub fn encode_utf16(&self) -> EncodeUtf16<'_> {
+        self.as_str().encode_utf16()
+    }
+
+    /// Encodes the string as UTF-16 into `buffer` and returns the number of code units written.
+    ///
+    /// No terminator is written; callers needing a wide C string can append a `0` after the
+    /// returned length.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` (counted in code units) if `buffer` is too short, in which case
+    /// `buffer` is left untouched.
+    pub fn encode_utf16_into(&self, buffer: &mut [u16]) -> Result<usize, CapacityError> {
+        let required = self.encode_utf16().count();
+        if required > buffer.len() {
+            return Err(CapacityError {
+                len: required,
+                capacity: buffer.len(),
+            });
+        }
+        for (unit, target) in self.encode_utf16().zip(buffer.iter_mut()) {
+            *target = unit;
+        }
+        Ok(required)
+    }
+}
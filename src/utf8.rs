@@ -0,0 +1,33 @@
+use std::str::Utf8Error;
+
+/// Views octets the crate itself stored as valid UTF-8.
+///
+/// Skips validation unless the `no-unsafe` feature asks for the checked conversion.
+#[cfg(not(feature = "no-unsafe"))]
+pub(crate) const fn from_utf8_stored(bytes: &[u8]) -> &str {
+    // SAFETY: only called on the used octets of strings, which are always valid UTF-8
+    unsafe { std::str::from_utf8_unchecked(bytes) }
+}
+
+/// Views octets the crate itself stored as valid UTF-8.
+///
+/// The `no-unsafe` feature validates them; the empty fallback is never taken.
+#[cfg(feature = "no-unsafe")]
+pub(crate) const fn from_utf8_stored(bytes: &[u8]) -> &str {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => "",
+    }
+}
+
+/// Validates octets as UTF-8, the single entry point for byte-slice inputs.
+///
+/// With the `simdutf8` feature valid input is checked with SIMD instructions; invalid input
+/// is re-checked by `std` to produce the detailed `Utf8Error`.
+pub(crate) fn from_utf8(bytes: &[u8]) -> Result<&str, Utf8Error> {
+    #[cfg(feature = "simdutf8")]
+    if let Ok(s) = simdutf8::basic::from_utf8(bytes) {
+        return Ok(s);
+    }
+    std::str::from_utf8(bytes)
+}
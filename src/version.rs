@@ -0,0 +1,98 @@
+use std::cmp::Ordering;
+use std::iter::FusedIterator;
+
+use crate::FixStr;
+
+const fn is_version(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut component_len = 0;
+    let mut leading_zero = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'.' {
+            if component_len == 0 {
+                return false;
+            }
+            component_len = 0;
+        } else if b.is_ascii_digit() {
+            if component_len == 0 {
+                leading_zero = b == b'0';
+            } else if leading_zero {
+                return false;
+            }
+            component_len += 1;
+        } else {
+            return false;
+        }
+        i += 1;
+    }
+    component_len > 0
+}
+
+crate::define_fixstr!(
+    /// A `FixStr` that holds a dotted version such as `"1.4.12"`.
+    ///
+    /// Each component is a decimal number without leading zeros. Versions are ordered
+    /// numerically component by component (`"1.10" > "1.9"`), and a version that extends
+    /// another sorts after it (`"1.4.0" > "1.4"`).
+    pub VersionFixStr<N>, N, const is_version, manual_ord
+);
+
+impl<const N: usize> VersionFixStr<N> {
+    /// Wraps `s` if it is a dotted version.
+    #[must_use]
+    pub fn from_fixstr(s: FixStr<N>) -> Option<Self> {
+        is_version(s.as_str()).then_some(Self(s))
+    }
+
+    /// Returns an iterator over the numeric components as strings of digits.
+    #[must_use]
+    pub fn components(&self) -> Components<'_> {
+        Components {
+            rest: Some(self.0.as_str()),
+        }
+    }
+}
+
+/// Compares component by component, where a component with more digits is the larger number.
+impl<const N: usize> Ord for VersionFixStr<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.components()
+            .map(|c| (c.len(), c))
+            .cmp(other.components().map(|c| (c.len(), c)))
+    }
+}
+
+impl<const N: usize> PartialOrd for VersionFixStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Iterator returned by [`VersionFixStr::components`].
+#[derive(Clone, Debug)]
+pub struct Components<'a> {
+    rest: Option<&'a str>,
+}
+
+impl<'a> Iterator for Components<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        match rest
+            .bytes()
+            .position(|b| b == b'.')
+            .and_then(|dot| rest.split_at_checked(dot))
+        {
+            Some((component, dot)) => {
+                self.rest = dot.get(1..);
+                Some(component)
+            }
+            None => self.rest.take(),
+        }
+    }
+}
+
+impl FusedIterator for Components<'_> {}
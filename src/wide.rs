@@ -0,0 +1,155 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::{CapacityError, FixStr};
+
+/// A fixed-capacity string with a 16-bit length, for capacities above the 255 octets
+/// that `FixStr` can fill, such as path-sized buffers.
+///
+/// Stores up to `N` octets (at most `u16::MAX`) inline and guarantees valid UTF-8.
+#[derive(Clone, Copy)]
+pub struct WideFixStr<const N: usize> {
+    inline: [u8; N],
+    len: u16,
+}
+
+impl<const N: usize> WideFixStr<N> {
+    /// Creates a new `WideFixStr` if the input fits within capacity.
+    ///
+    /// Returns `None` if the string is too long (> N octets) or exceeds `u16::MAX`.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        let len = u16::try_from(s.len()).ok()?;
+        let mut inline = [0; N];
+        inline.get_mut(..s.len())?.copy_from_slice(s.as_bytes());
+        Some(Self { inline, len })
+    }
+
+    /// Creates a new `WideFixStr`, reporting the lengths involved when the input does not fit.
+    ///
+    /// # Errors
+    /// Returns `CapacityError` if the string is too long (> N octets) or exceeds `u16::MAX`.
+    pub fn try_new(s: &str) -> Result<Self, CapacityError> {
+        Self::new(s).ok_or(CapacityError {
+            len: s.len(),
+            capacity: N,
+        })
+    }
+
+    /// Returns a string slice containing the entire string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        crate::utf8::from_utf8_stored(self.as_bytes())
+    }
+
+    /// Returns the string as a slice of octets.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.inline.get(..self.len as usize).unwrap_or_default()
+    }
+
+    /// Returns the length of the string in octets.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if the string is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total capacity in octets.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Default for WideFixStr<N> {
+    fn default() -> Self {
+        Self {
+            inline: [0; N],
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for WideFixStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WideFixStr(\"{}\")", self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Display for WideFixStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<const N: usize> PartialEq for WideFixStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Eq for WideFixStr<N> {}
+
+impl<const N: usize> Ord for WideFixStr<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl<const N: usize> PartialOrd for WideFixStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Hash for WideFixStr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> AsRef<str> for WideFixStr<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for WideFixStr<N> {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s).ok_or(format!(
+            "String '{s}' (len={}) exceeds capacity {N}",
+            s.len()
+        ))
+    }
+}
+
+impl<const N: usize> TryFrom<String> for WideFixStr<N> {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl<const N: usize> From<WideFixStr<N>> for String {
+    fn from(s: WideFixStr<N>) -> Self {
+        String::from(s.as_str())
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<FixStr<M>> for WideFixStr<N> {
+    type Error = CapacityError;
+
+    fn try_from(s: FixStr<M>) -> Result<Self, Self::Error> {
+        Self::try_new(s.as_str())
+    }
+}
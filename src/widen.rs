@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+
+use crate::FixStr;
+
+/// Infallible conversion into a type of equal or larger capacity.
+///
+/// Implemented for every `FixStr<N>` into `FixStr<M>`; using it with `M < N` is rejected at
+/// compile time, so generic code can widen without handling an error that never happens.
+///
+/// ```
+/// use fixstr::{FixStr, WidenInto};
+///
+/// let short: FixStr<8> = FixStr::new("hero").unwrap();
+/// let long: FixStr<16> = short.widen();
+/// assert_eq!(long.as_str(), "hero");
+/// ```
+///
+/// ```compile_fail
+/// use fixstr::{FixStr, WidenInto};
+///
+/// let long: FixStr<16> = FixStr::new("hero").unwrap();
+/// let short: FixStr<8> = long.widen();
+/// ```
+pub trait WidenInto<T> {
+    /// Converts `self` into the wider type.
+    fn widen(self) -> T;
+}
+
+impl<const N: usize, const M: usize> WidenInto<FixStr<M>> for FixStr<N> {
+    fn widen(self) -> FixStr<M> {
+        const { assert!(N <= M, "cannot widen a FixStr into a smaller capacity") };
+        let mut inline = [0; M];
+        for (target, b) in inline.iter_mut().zip(self.as_bytes()) {
+            *target = *b;
+        }
+        FixStr {
+            inline,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::FixStr;
+
+impl<const N: usize> FixStr<N> {
+    /// Returns the number of terminal columns the string occupies.
+    ///
+    /// CJK ideographs and most emoji take two columns, combining marks take none:
+    ///
+    /// ```
+    /// use fixstr::FixStr;
+    ///
+    /// let s: FixStr<16> = FixStr::new("名前 ok").unwrap();
+    /// assert_eq!(s.display_width(), 7);
+    /// ```
+    #[must_use]
+    pub fn display_width(&self) -> usize {
+        self.as_str().width()
+    }
+}
@@ -0,0 +1,10 @@
+use fixstr::aliases::{FixStr255, FixStr8, Name, Path64, Tag};
+
+#[test]
+fn test_alias_capacities() {
+    assert_eq!(FixStr8::default().capacity(), 8);
+    assert_eq!(FixStr255::default().capacity(), 255);
+    assert_eq!(Tag::default().capacity(), 16);
+    assert_eq!(Name::new("Player One").unwrap().as_str(), "Player One");
+    assert_eq!(Path64::default().capacity(), 64);
+}
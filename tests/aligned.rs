@@ -0,0 +1,48 @@
+use std::mem::{align_of, size_of};
+
+use fixstr::{Align64, Align8, AlignedFixStr, FixStr};
+
+#[test]
+fn test_layout() {
+    assert_eq!(align_of::<AlignedFixStr<8>>(), 16);
+    assert_eq!(size_of::<AlignedFixStr<8>>(), 16);
+    assert_eq!(size_of::<AlignedFixStr<16>>(), 32);
+    assert_eq!(align_of::<AlignedFixStr<8, Align8>>(), 8);
+    assert_eq!(align_of::<AlignedFixStr<8, Align64>>(), 64);
+
+    let keys = [AlignedFixStr::<20>::new("a").unwrap(); 4];
+    for key in &keys {
+        assert_eq!(std::ptr::from_ref(key).addr() % 16, 0);
+    }
+}
+
+#[test]
+fn test_new() {
+    let key: AlignedFixStr<16> = AlignedFixStr::new("player").unwrap();
+    assert_eq!(key.as_str(), "player");
+    assert_eq!(key.to_string(), "player");
+    assert_eq!(format!("{key:?}"), "AlignedFixStr(\"player\")");
+    assert!(AlignedFixStr::<4>::new("player").is_none());
+}
+
+#[test]
+fn test_conversions() {
+    let fix: FixStr<8> = FixStr::new("team").unwrap();
+    let key: AlignedFixStr<8> = fix.into();
+    assert_eq!(FixStr::from(key), fix);
+    assert_eq!(key.into_inner(), fix);
+    assert_eq!(AlignedFixStr::<8>::try_from("team").unwrap(), key);
+    assert!(AlignedFixStr::<2>::try_from(String::from("team")).is_err());
+}
+
+#[test]
+fn test_ordering_and_hash() {
+    use std::collections::HashSet;
+
+    let a: AlignedFixStr<8> = AlignedFixStr::new("alpha").unwrap();
+    let b: AlignedFixStr<8> = AlignedFixStr::new("beta").unwrap();
+    assert!(a < b);
+
+    let set: HashSet<_> = [a, b, a].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "ascii")]
+
+use ascii::AsciiStr;
+use fixstr::FixStr;
+
+#[test]
+fn test_as_ascii_str() {
+    let s: FixStr<8> = FixStr::new("GET").unwrap();
+    assert_eq!(s.as_ascii_str().unwrap(), "GET");
+
+    let non_ascii: FixStr<8> = FixStr::new("café").unwrap();
+    assert_eq!(non_ascii.as_ascii_str().unwrap_err().valid_up_to(), 3);
+}
+
+#[test]
+fn test_from_ascii_str() {
+    let ascii = AsciiStr::from_ascii("HTTP/1.1").unwrap();
+    let s: FixStr<8> = ascii.try_into().unwrap();
+    assert_eq!(s.as_str(), "HTTP/1.1");
+
+    assert!(FixStr::<4>::try_from(ascii).is_err());
+}
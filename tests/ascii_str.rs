@@ -0,0 +1,34 @@
+use fixstr::{FixAsciiStr, FixStr};
+
+#[test]
+fn test_new() {
+    let s: FixAsciiStr<8> = FixAsciiStr::new("GET").unwrap();
+    assert_eq!(s.as_str(), "GET");
+    assert_eq!(s.len(), 3);
+    assert_eq!(format!("{s:?}"), "FixAsciiStr(\"GET\")");
+
+    assert!(FixAsciiStr::<8>::new("café").is_none());
+    assert!(FixAsciiStr::<2>::new("GET").is_none());
+}
+
+#[test]
+fn test_byte_at_and_case() {
+    let mut s: FixAsciiStr<8> = FixAsciiStr::new("Post").unwrap();
+    assert_eq!(s.byte_at(0), Some(b'P'));
+    assert_eq!(s.byte_at(4), None);
+
+    assert_eq!(s.to_ascii_lowercase().as_str(), "post");
+    s.make_ascii_uppercase();
+    assert_eq!(s, FixAsciiStr::new("POST").unwrap());
+}
+
+#[test]
+fn test_conversions() {
+    let fix: FixStr<8> = FixStr::new("HEAD").unwrap();
+    let ascii = FixAsciiStr::try_from(fix).unwrap();
+    assert_eq!(ascii.as_fixstr(), &fix);
+    assert_eq!(FixStr::from(ascii), fix);
+
+    let err = FixAsciiStr::<8>::try_from("naïve").unwrap_err();
+    assert_eq!(err, "String 'naïve' is not ASCII");
+}
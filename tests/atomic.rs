@@ -0,0 +1,38 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+use fixstr::{AtomicFixStr, FixStr};
+
+#[test]
+fn test_store_and_load() {
+    let status: AtomicFixStr<16> = AtomicFixStr::default();
+    assert_eq!(status.load().as_str(), "");
+
+    status.store(FixStr::new("loading").unwrap());
+    assert_eq!(status.load().as_str(), "loading");
+    status.store(FixStr::new("ok").unwrap());
+    assert_eq!(status.load().as_str(), "ok");
+    assert_eq!(format!("{status:?}"), "AtomicFixStr(\"ok\")");
+}
+
+#[test]
+fn test_readers_never_see_torn_values() {
+    let values = ["ééééé", "aaaaaaaaaa", "x", "€€€"].map(|s| FixStr::<16>::new(s).unwrap());
+    let status = AtomicFixStr::new(values[0]);
+    let done = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        for _ in 0..3 {
+            scope.spawn(|| {
+                while !done.load(Ordering::Relaxed) {
+                    let value = status.load();
+                    assert!(values.contains(&value), "torn read: {value:?}");
+                }
+            });
+        }
+        for i in 0..20_000 {
+            status.store(values[i % values.len()]);
+        }
+        done.store(true, Ordering::Relaxed);
+    });
+}
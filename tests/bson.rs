@@ -0,0 +1,20 @@
+#![cfg(feature = "bson")]
+
+use bson::Bson;
+use fixstr::FixStr;
+
+#[test]
+fn test_into_bson() {
+    let s: FixStr<8> = FixStr::new("alice").unwrap();
+    assert_eq!(Bson::from(s), Bson::String("alice".to_string()));
+}
+
+#[test]
+fn test_from_bson() {
+    let value = Bson::String("alice".to_string());
+    let s: FixStr<8> = (&value).try_into().unwrap();
+    assert_eq!(s.as_str(), "alice");
+
+    assert!(FixStr::<4>::try_from(&value).is_err());
+    assert!(FixStr::<8>::try_from(Bson::Int32(42)).is_err());
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "bstr")]
+
+use bstr::{BStr, ByteSlice};
+use fixstr::FixStr;
+
+#[test]
+fn test_as_bstr() {
+    let s: FixStr<16> = FixStr::new(" key value ").unwrap();
+    let b = s.as_bstr();
+    assert_eq!(b.find("value"), Some(5));
+    assert_eq!(b.trim(), b"key value");
+    assert_eq!(
+        b.fields().collect::<Vec<_>>(),
+        [b"key".as_slice(), b"value"]
+    );
+}
+
+#[test]
+fn test_bstr_eq() {
+    let s: FixStr<8> = FixStr::new("abc").unwrap();
+    assert_eq!(s, *BStr::new("abc"));
+    assert_eq!(*BStr::new("abc"), s);
+    assert_ne!(s, *BStr::new("abd"));
+}
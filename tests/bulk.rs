@@ -0,0 +1,38 @@
+use fixstr::{CapacityError, FixStr};
+
+#[test]
+fn test_try_from_array_all() {
+    let names = FixStr::<8>::try_from_array_all(["idle", "walk", "run"]).unwrap();
+    assert_eq!(names.map(String::from), ["idle", "walk", "run"]);
+
+    let empty: [FixStr<8>; 0] = FixStr::try_from_array_all([]).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_try_from_array_all_reports_first_error() {
+    let error = FixStr::<4>::try_from_array_all(["idle", "jumping", "running"]).unwrap_err();
+    assert_eq!(
+        error,
+        (
+            1,
+            CapacityError {
+                len: 7,
+                capacity: 4
+            }
+        )
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_try_from_slice_all() {
+    let table = ["idle", "walk", "run"];
+    let names = FixStr::<8>::try_from_slice_all(&table).unwrap();
+    assert_eq!(names.len(), 3);
+    assert_eq!(names[2].as_str(), "run");
+
+    let error = FixStr::<3>::try_from_slice_all(&table).unwrap_err();
+    assert_eq!(error.0, 0);
+    assert_eq!(error.1.len, 4);
+}
@@ -0,0 +1,38 @@
+use fixstr::{FixByteStr, FixStr};
+
+#[test]
+fn test_new() {
+    let s: FixByteStr<8> = FixByteStr::new(b"ab\xFFc").unwrap();
+    assert_eq!(s.as_bytes(), b"ab\xFFc");
+    assert_eq!(s.len(), 4);
+    assert!(s.to_str().is_err());
+    assert!(FixByteStr::<2>::new(b"abc").is_none());
+}
+
+#[test]
+fn test_lossy_display() {
+    let s: FixByteStr<8> = FixByteStr::new(b"ab\xFFc").unwrap();
+    #[cfg(not(feature = "panic-free"))]
+    assert_eq!(s.to_str_lossy(), "ab\u{FFFD}c");
+    assert_eq!(s.to_string(), "ab\u{FFFD}c");
+    assert_eq!(format!("{s:?}"), "FixByteStr(\"ab\\xFFc\")");
+}
+
+#[test]
+fn test_conversions() {
+    let fix: FixStr<8> = FixStr::new("näme").unwrap();
+    let bytes = FixByteStr::from(fix);
+    assert_eq!(bytes.to_str().unwrap(), "näme");
+    assert_eq!(FixStr::try_from(bytes).unwrap(), fix);
+
+    let invalid = FixByteStr::<8>::try_from(&b"\xC3"[..]).unwrap();
+    assert!(FixStr::try_from(invalid).is_err());
+}
+
+#[test]
+fn test_to_str_error_details() {
+    let s: FixByteStr<16> = FixByteStr::new(b"caf\xC3\xA9 \xFF!").unwrap();
+    let error = s.to_str().unwrap_err();
+    assert_eq!(error.valid_up_to(), 6);
+    assert_eq!(error.error_len(), Some(1));
+}
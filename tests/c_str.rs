@@ -0,0 +1,42 @@
+use std::ffi::{c_char, CStr};
+
+use fixstr::{FixCString, FixStr};
+
+#[test]
+fn test_new() {
+    let s: FixCString<8> = FixCString::new("label").unwrap();
+    assert_eq!(s.as_str(), "label");
+    assert_eq!(s.as_c_str(), c"label");
+    assert_eq!(s.as_c_str().to_bytes_with_nul().len(), 6);
+    assert_eq!(s.capacity(), 7);
+
+    assert!(FixCString::<8>::new("1234567").is_some());
+    assert!(FixCString::<8>::new("12345678").is_none());
+    assert!(FixCString::<8>::new("a\0b").is_none());
+    assert_eq!(FixCString::<8>::default().as_c_str(), c"");
+}
+
+#[test]
+fn test_as_ptr() {
+    extern "C" {
+        fn strlen(s: *const c_char) -> usize;
+    }
+
+    let s: FixCString<16> = FixCString::new("glLabel").unwrap();
+    // SAFETY: `as_ptr` points to a NUL-terminated string that outlives the call
+    assert_eq!(unsafe { strlen(s.as_ptr()) }, 7);
+    // SAFETY: as above
+    assert_eq!(unsafe { CStr::from_ptr(s.as_ptr()) }, c"glLabel");
+}
+
+#[test]
+fn test_conversions() {
+    let s = FixCString::<4>::try_from("abc").unwrap();
+    assert_eq!(FixStr::from(s).as_str(), "abc");
+
+    let err = FixCString::<4>::try_from("a\0").unwrap_err();
+    assert_eq!(
+        err,
+        "String 'a\\0' (len=2) contains NUL or exceeds capacity 3"
+    );
+}
@@ -0,0 +1,81 @@
+use fixstr::FixStr;
+
+#[test]
+fn test_make_ascii_case() {
+    let mut s: FixStr<16> = FixStr::new("Grüße HELP").unwrap();
+    s.make_ascii_lowercase();
+    assert_eq!(s.as_str(), "grüße help");
+    s.make_ascii_uppercase();
+    assert_eq!(s.as_str(), "GRüßE HELP");
+    assert_eq!(s, FixStr::<16>::new("GRüßE HELP").unwrap());
+}
+
+#[test]
+fn test_to_ascii_case() {
+    let s: FixStr<16> = FixStr::new("Quit").unwrap();
+    assert_eq!(s.to_ascii_uppercase().as_str(), "QUIT");
+    assert_eq!(s.to_ascii_lowercase().as_str(), "quit");
+    assert_eq!(s.as_str(), "Quit");
+}
+
+#[test]
+fn test_try_to_unicode_case() {
+    let s: FixStr<16> = FixStr::new("Straße").unwrap();
+    assert_eq!(s.try_to_uppercase().unwrap().as_str(), "STRASSE");
+    assert_eq!(s.try_to_lowercase().unwrap().as_str(), "straße");
+
+    let s: FixStr<16> = FixStr::new("ÅNGSTRÖM").unwrap();
+    assert_eq!(s.try_to_lowercase().unwrap().as_str(), "ångström");
+}
+
+#[test]
+fn test_try_to_uppercase_exceeding_capacity() {
+    let s: FixStr<2> = FixStr::new("ŉ").unwrap();
+    let err = s.try_to_uppercase().unwrap_err();
+    assert_eq!((err.len, err.capacity), (3, 2));
+
+    let s: FixStr<2> = FixStr::new("Ⱥ").unwrap();
+    assert!(s.try_to_lowercase().is_err());
+}
+
+#[test]
+fn test_ignore_ascii_case() {
+    use core::cmp::Ordering;
+
+    let s: FixStr<16> = FixStr::new("/HELP").unwrap();
+    assert!(s.eq_ignore_ascii_case("/help"));
+    assert!(!s.eq_ignore_ascii_case("/hel"));
+    assert_eq!(s.cmp_ignore_ascii_case("/help"), Ordering::Equal);
+    assert_eq!(s.cmp_ignore_ascii_case("/Help/all"), Ordering::Less);
+    assert_eq!(s.cmp_ignore_ascii_case("/Abc"), Ordering::Greater);
+}
+
+#[test]
+fn test_capitalize() {
+    let s: FixStr<16> = FixStr::new("warrior class").unwrap();
+    assert_eq!(s.capitalize().as_str(), "Warrior class");
+    assert_eq!(FixStr::<4>::default().capitalize().as_str(), "");
+
+    let s: FixStr<16> = FixStr::new("élan").unwrap();
+    assert_eq!(s.capitalize().as_str(), "élan");
+}
+
+#[cfg(not(feature = "no-unsafe"))]
+#[test]
+fn test_capitalize_leaves_spare_capacity() {
+    let mut s = FixStr::<4>::default();
+    s.spare_capacity_mut()[0] = b'a';
+    let mut capitalized = s.capitalize();
+    // SAFETY: "a" is valid UTF-8 within capacity
+    unsafe { capitalized.set_len(1) };
+    assert_eq!(capitalized.as_str(), "a");
+}
+
+#[test]
+fn test_to_title_case_ascii() {
+    let s: FixStr<32> = FixStr::new("player_one-READY now").unwrap();
+    assert_eq!(s.to_title_case_ascii().as_str(), "Player_One-Ready Now");
+
+    let s: FixStr<32> = FixStr::new("naïve ÜBER").unwrap();
+    assert_eq!(s.to_title_case_ascii().as_str(), "Naïve Über");
+}
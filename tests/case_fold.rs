@@ -0,0 +1,14 @@
+#![cfg(feature = "case-fold")]
+
+use fixstr::FixStr;
+
+#[test]
+fn test_eq_fold() {
+    let s: FixStr<16> = FixStr::new("Straße").unwrap();
+    assert!(s.eq_fold("STRASSE"));
+    assert!(s.eq_fold("strasse"));
+    assert!(!s.eq_fold("strase"));
+
+    let s: FixStr<16> = FixStr::new("ΣΊΣΥΦΟΣ").unwrap();
+    assert!(s.eq_fold("σίσυφος"));
+}
@@ -0,0 +1,36 @@
+use std::collections::{BTreeSet, HashMap};
+
+use fixstr::{CaselessFixStr, FixStr};
+
+#[test]
+fn test_caseless_eq_and_hash() {
+    let mut scores: HashMap<CaselessFixStr<16>, u32> = HashMap::new();
+    scores.insert("Alice".try_into().unwrap(), 10);
+    *scores.entry("ALICE".try_into().unwrap()).or_default() += 5;
+
+    assert_eq!(scores.len(), 1);
+    let key: CaselessFixStr<16> = "alice".try_into().unwrap();
+    assert_eq!(scores[&key], 15);
+
+    let (stored, _) = scores.iter().next().unwrap();
+    assert_eq!(stored.as_str(), "Alice");
+}
+
+#[test]
+fn test_caseless_ord() {
+    let mut names: BTreeSet<CaselessFixStr<16>> = BTreeSet::new();
+    for name in ["bob", "Alice", "ALICE", "Carol"] {
+        names.insert(name.try_into().unwrap());
+    }
+    let names: Vec<String> = names.iter().map(ToString::to_string).collect();
+    assert_eq!(names, ["Alice", "bob", "Carol"]);
+}
+
+#[test]
+fn test_caseless_conversions() {
+    let s: FixStr<8> = FixStr::new("Key").unwrap();
+    let caseless = CaselessFixStr::from(s);
+    assert_eq!(format!("{caseless:?}"), "CaselessFixStr(\"Key\")");
+    assert_eq!(FixStr::from(caseless), s);
+    assert_eq!(caseless.into_inner(), s);
+}
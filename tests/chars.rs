@@ -0,0 +1,74 @@
+use fixstr::FixStr;
+
+#[test]
+fn test_char_at_and_nth_char() {
+    let s: FixStr<16> = FixStr::new("a€b").unwrap();
+    assert_eq!(s.char_at(0), Some('a'));
+    assert_eq!(s.char_at(1), Some('€'));
+    assert_eq!(s.char_at(2), None);
+    assert_eq!(s.char_at(4), Some('b'));
+    assert_eq!(s.char_at(5), None);
+
+    assert_eq!(s.nth_char(1), Some('€'));
+    assert_eq!(s.nth_char(2), Some('b'));
+    assert_eq!(s.nth_char(3), None);
+}
+
+#[test]
+fn test_char_indices() {
+    let s: FixStr<16> = FixStr::new("a€b").unwrap();
+    let indices: Vec<(usize, char)> = s.char_indices().collect();
+    assert_eq!(indices, [(0, 'a'), (1, '€'), (4, 'b')]);
+}
+
+#[test]
+fn test_chars_and_bytes() {
+    let s: FixStr<16> = FixStr::new("añ").unwrap();
+    assert_eq!(s.chars().rev().collect::<String>(), "ña");
+    assert_eq!(s.bytes().collect::<Vec<_>>(), [b'a', 0xC3, 0xB1]);
+    assert_eq!(s.bytes().next_back(), Some(0xB1));
+    assert_eq!(s.bytes().len(), 3);
+}
+
+#[test]
+fn test_char_boundaries() {
+    let s: FixStr<16> = FixStr::new("a€b").unwrap();
+    assert!(s.is_char_boundary(1));
+    assert!(!s.is_char_boundary(2));
+    assert!(s.is_char_boundary(5));
+    assert!(!s.is_char_boundary(6));
+
+    assert_eq!(s.floor_char_boundary(3), 1);
+    assert_eq!(s.ceil_char_boundary(3), 4);
+    assert_eq!(s.floor_char_boundary(4), 4);
+    assert_eq!(s.floor_char_boundary(9), 5);
+    assert_eq!(s.ceil_char_boundary(9), 5);
+}
+
+#[test]
+fn test_char_predicates() {
+    let s: FixStr<16> = FixStr::new("Player42").unwrap();
+    assert!(s.is_alphanumeric());
+    assert!(!s.is_alphabetic());
+    assert!(!s.is_numeric());
+
+    let s: FixStr<16> = FixStr::new("Zoë").unwrap();
+    assert!(s.is_alphabetic());
+
+    let s: FixStr<16> = FixStr::new("2024¾").unwrap();
+    assert!(s.is_numeric());
+    assert!(!s.all_chars(|c| c.is_ascii_digit()));
+
+    let s: FixStr<16> = FixStr::new("a-b").unwrap();
+    assert!(!s.is_alphanumeric());
+    assert!(s.all_chars(|c| c.is_ascii_lowercase() || c == '-'));
+}
+
+#[test]
+fn test_char_predicates_empty() {
+    let s = FixStr::<4>::default();
+    assert!(s.all_chars(|_| false));
+    assert!(!s.is_alphanumeric());
+    assert!(!s.is_numeric());
+    assert!(!s.is_alphabetic());
+}
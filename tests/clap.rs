@@ -0,0 +1,33 @@
+#![cfg(feature = "clap")]
+
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, Command};
+use fixstr::FixStr;
+
+fn command() -> Command {
+    Command::new("server").arg(
+        Arg::new("region")
+            .long("region")
+            .value_parser(value_parser!(FixStr<8>)),
+    )
+}
+
+#[test]
+fn test_parse_value() {
+    let matches = command()
+        .try_get_matches_from(["server", "--region", "eu-west"])
+        .unwrap();
+    let region = matches.get_one::<FixStr<8>>("region").unwrap();
+    assert_eq!(region.as_str(), "eu-west");
+}
+
+#[test]
+fn test_value_too_long() {
+    let err = command()
+        .try_get_matches_from(["server", "--region", "ap-southeast"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains(
+        "invalid value 'ap-southeast' for '--region <region>': value too long (max 8 bytes)"
+    ));
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "collation")]
+
+use fixstr::FixStr;
+
+#[test]
+fn test_collation_key_order() {
+    let mut names: Vec<FixStr<16>> = ["zoe", "Émile", "adam", "Zoë", "eve", "Adam"]
+        .into_iter()
+        .map(|name| FixStr::new(name).unwrap())
+        .collect();
+    names.sort_by_key(|name| name.collation_key::<33>().unwrap());
+
+    let names: Vec<&str> = names.iter().map(FixStr::as_str).collect();
+    assert_eq!(names, ["Adam", "adam", "Émile", "eve", "Zoë", "zoe"]);
+}
+
+#[test]
+fn test_collation_key_exceeding_capacity() {
+    let name: FixStr<8> = FixStr::new("Zoë").unwrap();
+    assert!(name.collation_key::<7>().is_err());
+    assert_eq!(name.collation_key::<8>().unwrap().len(), 8);
+}
@@ -0,0 +1,41 @@
+use std::cmp::Ordering;
+
+use fixstr::{fixconcat, FixStr};
+
+const HELP: FixStr<4> = fixconcat!("help");
+const HELD: FixStr<4> = fixconcat!("held");
+
+const fn is_sorted<const N: usize>(names: &[FixStr<N>]) -> bool {
+    let mut i = 1;
+    while i < names.len() {
+        if !matches!(names[i - 1].const_cmp(&names[i]), Ordering::Less) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[test]
+fn test_const_eq() {
+    const { assert!(HELP.const_eq(&HELP)) };
+    const { assert!(!HELP.const_eq(&HELD)) };
+}
+
+#[test]
+fn test_const_cmp_matches_ord() {
+    let words = ["", "a", "ab", "abc", "b", "é", "e"];
+    for a in words {
+        for b in words {
+            let (x, y) = (FixStr::<4>::new(a).unwrap(), FixStr::<4>::new(b).unwrap());
+            assert_eq!(x.const_cmp(&y), x.cmp(&y), "{a:?} vs {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_const_cmp_in_const_table() {
+    const TABLE: [FixStr<4>; 3] = [fixconcat!("he", "ap"), HELD, HELP];
+    const { assert!(is_sorted(&TABLE)) };
+    assert!(!is_sorted(&[HELP, HELD]));
+}
@@ -0,0 +1,29 @@
+use fixstr::{fixconcat, FixStr};
+
+const MAGIC: FixStr<4> = fixconcat!("FX", "S1");
+
+#[test]
+fn test_fixconcat_literals() {
+    assert_eq!(MAGIC.as_str(), "FXS1");
+    assert_eq!(MAGIC.capacity(), 4);
+}
+
+#[test]
+fn test_fixconcat_const_fixstr() {
+    const HELLO: FixStr<10> = fixconcat!(MAGIC, "/hello", "");
+    assert_eq!(HELLO.as_str(), "FXS1/hello");
+    assert_eq!(HELLO.capacity(), 10);
+}
+
+#[test]
+fn test_fixconcat_unicode() {
+    let unicode = fixconcat!("é", "€",);
+    assert_eq!(unicode.as_str(), "é€");
+    assert_eq!(unicode.capacity(), 5);
+}
+
+#[test]
+#[should_panic(expected = "valid UTF-8")]
+fn test_concat_rejects_invalid_utf8() {
+    let _ = fixstr::__private::concat::<1>(&[&[0xC3]]);
+}
@@ -0,0 +1,34 @@
+use fixstr::FixStr;
+
+#[test]
+fn test_ct_eq() {
+    let pin: FixStr<8> = FixStr::new("4711").unwrap();
+    assert!(pin.ct_eq(&FixStr::new("4711").unwrap()));
+    assert!(!pin.ct_eq(&FixStr::new("4712").unwrap()));
+    assert!(!pin.ct_eq(&FixStr::new("471").unwrap()));
+    assert!(!pin.ct_eq(&FixStr::new("47110").unwrap()));
+    assert!(FixStr::<8>::default().ct_eq(&FixStr::default()));
+}
+
+#[test]
+fn test_ct_eq_matches_eq() {
+    let words = ["", "a", "ab", "b", "\0", "a\0"];
+    for a in words {
+        for b in words {
+            let (x, y) = (FixStr::<4>::new(a).unwrap(), FixStr::<4>::new(b).unwrap());
+            assert_eq!(x.ct_eq(&y), x == y, "{a:?} vs {b:?}");
+        }
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn test_subtle_constant_time_eq() {
+    use subtle::ConstantTimeEq;
+
+    let token: FixStr<16> = FixStr::new("s3cr3t").unwrap();
+    let same: FixStr<16> = FixStr::new("s3cr3t").unwrap();
+    let other: FixStr<16> = FixStr::new("s3cr3T").unwrap();
+    assert!(bool::from(ConstantTimeEq::ct_eq(&token, &same)));
+    assert!(!bool::from(ConstantTimeEq::ct_eq(&token, &other)));
+}
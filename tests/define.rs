@@ -0,0 +1,50 @@
+use fixstr::{FixStr, ValidationError};
+
+fn valid_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fixstr::define_fixstr!(
+    /// A player name.
+    pub PlayerName, 12, |s| !s.is_empty() && s.chars().all(valid_name_char)
+);
+
+fn is_lowercase(s: &str) -> bool {
+    !s.chars().any(char::is_uppercase)
+}
+
+fixstr::define_fixstr!(Tag, 8, is_lowercase);
+
+#[test]
+fn test_new() {
+    let name = PlayerName::new("dark_knight").unwrap();
+    assert_eq!(name.as_str(), "dark_knight");
+    assert_eq!(name.len(), 11);
+    assert_eq!(format!("{name:?}"), "PlayerName(\"dark_knight\")");
+    assert_eq!(name.to_string(), "dark_knight");
+
+    let tag: Tag = "rpg".parse().unwrap();
+    assert_eq!(tag.into_inner(), FixStr::<8>::new("rpg").unwrap());
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        PlayerName::new("dark knight"),
+        Err(ValidationError::Invalid {
+            type_name: "PlayerName"
+        })
+    );
+    let err = PlayerName::try_from("a_very_long_name").unwrap_err();
+    assert_eq!(
+        err,
+        ValidationError::Capacity(fixstr::CapacityError {
+            len: 16,
+            capacity: 12
+        })
+    );
+    assert_eq!(
+        "RPG".parse::<Tag>().unwrap_err().to_string(),
+        "String is not a valid Tag"
+    );
+}
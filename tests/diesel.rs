@@ -0,0 +1,56 @@
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use fixstr::FixStr;
+
+diesel::table! {
+    players (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+#[derive(Queryable, Insertable, Debug, PartialEq)]
+#[diesel(table_name = players)]
+struct Player {
+    id: i32,
+    name: FixStr<8>,
+}
+
+fn connection() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE players (id INTEGER PRIMARY KEY, name VARCHAR(16) NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+    conn
+}
+
+#[test]
+fn test_insert_and_load() {
+    let mut conn = connection();
+    let player = Player {
+        id: 1,
+        name: FixStr::new("alice").unwrap(),
+    };
+    diesel::insert_into(players::table)
+        .values(&player)
+        .execute(&mut conn)
+        .unwrap();
+
+    let loaded: Player = players::table
+        .filter(players::name.eq(FixStr::<8>::new("alice").unwrap()))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(loaded, player);
+}
+
+#[test]
+fn test_load_rejects_overlong_text() {
+    let mut conn = connection();
+    diesel::insert_into(players::table)
+        .values((players::id.eq(1), players::name.eq("much too long")))
+        .execute(&mut conn)
+        .unwrap();
+
+    let loaded: QueryResult<Player> = players::table.first(&mut conn);
+    assert!(loaded.is_err());
+}
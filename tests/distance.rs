@@ -0,0 +1,31 @@
+#![cfg(feature = "edit-distance")]
+
+use fixstr::FixStr;
+
+#[test]
+fn test_edit_distance() {
+    let s: FixStr<16> = FixStr::new("kitten").unwrap();
+    assert_eq!(s.edit_distance("sitting"), 3);
+    assert_eq!(s.edit_distance("kitten"), 0);
+    assert_eq!(s.edit_distance(""), 6);
+    assert_eq!(FixStr::<16>::default().edit_distance("abc"), 3);
+
+    let s: FixStr<16> = FixStr::new("café").unwrap();
+    assert_eq!(s.edit_distance("cafe"), 1);
+}
+
+#[test]
+fn test_similarity_for_suggestions() {
+    let commands: Vec<FixStr<16>> = ["help", "quit", "kick", "teleport"]
+        .iter()
+        .map(|s| FixStr::new(s).unwrap())
+        .collect();
+    let best = commands
+        .iter()
+        .max_by(|a, b| a.similarity("telport").total_cmp(&b.similarity("telport")))
+        .unwrap();
+    assert_eq!(best.as_str(), "teleport");
+
+    assert_eq!(FixStr::<4>::default().similarity(""), 1.0);
+    assert_eq!(FixStr::<4>::new("ab").unwrap().similarity("cd"), 0.0);
+}
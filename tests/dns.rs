@@ -0,0 +1,37 @@
+use fixstr::{DnsLabel, Hostname, ValidationError};
+
+#[test]
+fn test_dns_label() {
+    for label in ["eu-west-1", "a", "3com", &"x".repeat(63)] {
+        assert!(DnsLabel::new(label).is_ok(), "{label}");
+    }
+    for label in ["", "-lobby", "lobby-", "lob_by", "lob.by", "lobbý"] {
+        assert!(DnsLabel::new(label).is_err(), "{label}");
+    }
+    assert!(matches!(
+        DnsLabel::new(&"x".repeat(64)),
+        Err(ValidationError::Capacity(_))
+    ));
+}
+
+#[test]
+fn test_hostname() {
+    let host: Hostname = "match-01.eu.example.com".parse().unwrap();
+    assert_eq!(host.as_str(), "match-01.eu.example.com");
+
+    for host in [
+        "",
+        "example..com",
+        ".example.com",
+        "example.com.",
+        "-a.example.com",
+    ] {
+        assert_eq!(
+            Hostname::new(host),
+            Err(ValidationError::Invalid {
+                type_name: "Hostname"
+            }),
+            "{host}"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "equivalent")]
+
+use fixstr::FixStr;
+
+#[test]
+fn test_hashbrown_lookup_by_str() {
+    let mut map = hashbrown::HashMap::new();
+    map.insert(FixStr::<16>::new("jump").unwrap(), 1);
+    map.insert(FixStr::<16>::new("crouch").unwrap(), 2);
+
+    assert_eq!(map.get("jump"), Some(&1));
+    assert_eq!(map.get("crouch"), Some(&2));
+    assert_eq!(map.get("run"), None);
+}
+
+#[test]
+fn test_indexmap_lookup_by_str() {
+    let mut map = indexmap::IndexMap::new();
+    map.insert(FixStr::<16>::new("jump").unwrap(), 1);
+    map.insert(FixStr::<16>::new("crouch").unwrap(), 2);
+
+    assert_eq!(map.get("crouch"), Some(&2));
+    assert_eq!(map.get_index_of("jump"), Some(0));
+    assert_eq!(map.get("run"), None);
+}
@@ -4,6 +4,7 @@ use fixstr::FixStr;
 fn test_basic_creation() {
     let s: FixStr<8> = FixStr::new("abc").unwrap();
     assert_eq!(s.as_str(), "abc");
+    assert_eq!(s.as_bytes(), b"abc");
     assert_eq!(s.len(), 3);
     assert_eq!(s.capacity(), 8);
 }
@@ -43,6 +44,7 @@ fn test_conversions() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic(expected = "String 'too long' (len=8) exceeds capacity 2")]
 fn test_unchecked_panics() {
     let _s: FixStr<2> = FixStr::new_unchecked("too long");
@@ -60,3 +62,73 @@ fn debug_string() {
     let s: FixStr<8> = FixStr::new("abc").unwrap();
     assert_eq!(format!("{:?}", s), "FixStr(\"abc\")");
 }
+
+#[test]
+fn test_hash_matches_str() {
+    use std::hash::BuildHasher;
+
+    let state = std::collections::hash_map::RandomState::new();
+    let s: FixStr<8> = FixStr::new("abc").unwrap();
+    assert_eq!(state.hash_one(s), state.hash_one("abc"));
+}
+
+#[test]
+fn test_try_new() {
+    let s: FixStr<4> = FixStr::try_new("abcd").unwrap();
+    assert_eq!(s.as_str(), "abcd");
+
+    let err = FixStr::<4>::try_new("abcde").unwrap_err();
+    assert_eq!(err.len, 5);
+    assert_eq!(err.capacity, 4);
+    assert_eq!(err.to_string(), "String (len=5) exceeds capacity 4");
+}
+
+#[test]
+fn test_eq_and_ord() {
+    let a: FixStr<64> = FixStr::new("alpha").unwrap();
+    let b: FixStr<64> = FixStr::new("beta").unwrap();
+    let a2: FixStr<64> = FixStr::new("alpha").unwrap();
+    let with_nul: FixStr<64> = FixStr::new("alpha\0").unwrap();
+
+    assert_eq!(a, a2);
+    assert_ne!(a, b);
+    assert_ne!(a, with_nul);
+    assert!(a < b);
+    assert!(a < with_nul);
+    assert_eq!(a.cmp(&a2), std::cmp::Ordering::Equal);
+
+    let mut names: Vec<FixStr<64>> = ["b", "", "ab", "a"]
+        .iter()
+        .map(|s| FixStr::new(s).unwrap())
+        .collect();
+    names.sort();
+    let sorted: Vec<&str> = names.iter().map(FixStr::as_str).collect();
+    assert_eq!(sorted, ["", "a", "ab", "b"]);
+}
+
+#[test]
+fn test_resize() {
+    let s: FixStr<8> = FixStr::new("hero").unwrap();
+    let wider: FixStr<32> = s.resize().unwrap();
+    assert_eq!(wider.as_str(), "hero");
+    assert_eq!(wider.capacity(), 32);
+
+    assert_eq!(s.resize::<4>().unwrap().as_str(), "hero");
+    let err = s.resize::<3>().unwrap_err();
+    assert_eq!((err.len, err.capacity), (4, 3));
+}
+
+#[test]
+fn test_widen() {
+    use fixstr::WidenInto;
+
+    fn store<T: WidenInto<FixStr<32>>>(value: T) -> FixStr<32> {
+        value.widen()
+    }
+
+    let s: FixStr<8> = FixStr::new("hero").unwrap();
+    let wider = store(s);
+    assert_eq!(wider.as_str(), "hero");
+    assert_eq!(wider, FixStr::<32>::new("hero").unwrap());
+    assert_eq!(store(FixStr::<32>::new("same").unwrap()).as_str(), "same");
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "alloc")]
+
+use fixstr::{FixStr, FlexStr};
+
+#[test]
+fn test_inline_or_heap() {
+    let short: FlexStr<8> = FlexStr::new("sword");
+    assert!(short.is_inline());
+    assert_eq!(short.as_str(), "sword");
+
+    let long: FlexStr<8> = FlexStr::new("legendary sword");
+    assert!(!long.is_inline());
+    assert_eq!(long.as_str(), "legendary sword");
+    assert_eq!(long.len(), 15);
+}
+
+#[test]
+fn test_traits() {
+    let from_string = FlexStr::<8>::from(String::from("legendary sword"));
+    let from_str = FlexStr::<8>::from("legendary sword");
+    assert_eq!(from_string, from_str);
+    assert!(FlexStr::<8>::from("axe") < from_str);
+    assert_eq!(format!("{from_str:?}"), "FlexStr(\"legendary sword\")");
+    assert_eq!(String::from(from_str), "legendary sword");
+
+    let fix: FixStr<8> = FixStr::new("bow").unwrap();
+    assert_eq!(FlexStr::from(fix).as_str(), "bow");
+    assert!(FlexStr::<8>::default().is_empty());
+}
@@ -0,0 +1,174 @@
+use fixstr::FixStr;
+
+#[test]
+fn test_from_int_padded() {
+    let padded = |value: i64, width| FixStr::<16>::from_int_padded(value, width).unwrap();
+    assert_eq!(padded(42, 5).as_str(), "00042");
+    assert_eq!(padded(-42, 5).as_str(), "-0042");
+    assert_eq!(padded(0, 3).as_str(), "000");
+    assert_eq!(padded(123_456, 3).as_str(), "123456");
+    assert_eq!(padded(7, 0).as_str(), "7");
+
+    for (value, width) in [(42_i64, 5), (-42, 5), (i64::MIN, 0), (i64::MAX, 25)] {
+        let padded = FixStr::<32>::from_int_padded(value, width).unwrap();
+        assert_eq!(padded.as_str(), format!("{value:0width$}"));
+    }
+    assert_eq!(
+        FixStr::<8>::from_int_padded(255_u8, 4).unwrap().as_str(),
+        "0255"
+    );
+    assert_eq!(
+        FixStr::<40>::from_int_padded(u64::MAX, 0).unwrap().as_str(),
+        u64::MAX.to_string()
+    );
+}
+
+#[test]
+fn test_from_int_padded_exceeding_capacity() {
+    let err = FixStr::<4>::from_int_padded(42, 5).unwrap_err();
+    assert_eq!((err.len, err.capacity), (5, 4));
+    assert!(FixStr::<4>::from_int_padded(12_345, 0).is_err());
+}
+
+#[cfg(not(feature = "panic-free"))]
+#[test]
+fn test_from_f64_exp() {
+    let exp = |value: f64, precision| FixStr::<16>::from_f64_exp(value, precision).unwrap();
+    assert_eq!(exp(0.000_123_4, 2).as_str(), "1.23e-4");
+    assert_eq!(exp(-98_765.0, 1).as_str(), "-9.9e4");
+    assert_eq!(exp(1.0, 0).as_str(), "1e0");
+    assert_eq!(exp(f64::INFINITY, 3).as_str(), "inf");
+
+    let err = FixStr::<4>::from_f64_exp(0.000_123_4, 2).unwrap_err();
+    assert_eq!((err.len, err.capacity), (7, 4));
+}
+
+#[cfg(not(feature = "panic-free"))]
+#[test]
+fn test_from_f64_si() {
+    let si = |value: f64, precision| FixStr::<16>::from_f64_si(value, precision).unwrap();
+    assert_eq!(si(1500.0, 1).as_str(), "1.5k");
+    assert_eq!(si(3_210_000.0, 1).as_str(), "3.2M");
+    assert_eq!(si(-42_000.0, 0).as_str(), "-42k");
+    assert_eq!(si(999.0, 1).as_str(), "999.0");
+    assert_eq!(si(999.96, 1).as_str(), "1.0k");
+    assert_eq!(si(0.0032, 1).as_str(), "3.2m");
+    assert_eq!(si(0.000_004_7, 2).as_str(), "4.70µ");
+    assert_eq!(si(0.0, 1).as_str(), "0.0");
+    assert_eq!(si(5e21, 0).as_str(), "5000E");
+    assert_eq!(si(f64::NAN, 1).as_str(), "NaN");
+
+    assert!(FixStr::<3>::from_f64_si(1500.0, 1).is_err());
+}
+
+#[test]
+fn test_from_duration() {
+    use fixstr::DurationStyle::{Clock, Compact};
+    use std::time::Duration;
+
+    let format =
+        |secs, style| FixStr::<16>::from_duration(Duration::from_secs(secs), style).unwrap();
+    assert_eq!(format(192, Compact).as_str(), "3m 12s");
+    assert_eq!(format(3605, Compact).as_str(), "1h 5s");
+    assert_eq!(format(45, Compact).as_str(), "45s");
+    assert_eq!(format(192, Clock).as_str(), "00:03:12");
+    assert_eq!(format(100 * 3600 + 1, Clock).as_str(), "100:00:01");
+
+    let short = Duration::from_millis(250);
+    assert_eq!(
+        FixStr::<8>::from_duration(short, Compact).unwrap().as_str(),
+        "250ms"
+    );
+    assert_eq!(
+        FixStr::<8>::from_duration(short, Clock).unwrap().as_str(),
+        "00:00:00"
+    );
+
+    let err = FixStr::<4>::from_duration(Duration::from_secs(192), Clock).unwrap_err();
+    assert_eq!((err.len, err.capacity), (8, 4));
+    let err = FixStr::<4>::from_duration(Duration::from_secs(192), Compact).unwrap_err();
+    assert_eq!(err.len, 6);
+}
+
+#[test]
+fn test_from_bytes_size() {
+    let size = |bytes| FixStr::<16>::from_bytes_size(bytes).unwrap();
+    assert_eq!(size(0).as_str(), "0 B");
+    assert_eq!(size(1023).as_str(), "1023 B");
+    assert_eq!(size(1024).as_str(), "1.0 KiB");
+    assert_eq!(size(1_258_291).as_str(), "1.2 MiB");
+    assert_eq!(size(1024 * 1024 - 1).as_str(), "1.0 MiB");
+    assert_eq!(size(5 << 30).as_str(), "5.0 GiB");
+    assert_eq!(size(u64::MAX).as_str(), "16.0 EiB");
+    // Ties round to even like `{:.1}`: 1280 B is exactly 1.25 KiB
+    assert_eq!(size(1280).as_str(), "1.2 KiB");
+    assert_eq!(size(1331).as_str(), "1.3 KiB");
+
+    let err = FixStr::<4>::from_bytes_size(1_258_291).unwrap_err();
+    assert_eq!((err.len, err.capacity), (7, 4));
+}
+
+#[cfg(not(feature = "panic-free"))]
+#[test]
+fn test_from_ratio_percent() {
+    assert_eq!(
+        FixStr::<8>::from_ratio_percent(7, 8, 1).unwrap().as_str(),
+        "87.5%"
+    );
+    assert_eq!(
+        FixStr::<8>::from_ratio_percent(1, 3, 0).unwrap().as_str(),
+        "33%"
+    );
+    assert_eq!(
+        FixStr::<8>::from_ratio_percent(3, 2, 2).unwrap().as_str(),
+        "150.00%"
+    );
+    assert_eq!(
+        FixStr::<8>::from_ratio_percent(1, 4, 0).unwrap().as_str(),
+        "25%"
+    );
+    assert_eq!(
+        FixStr::<8>::from_ratio_percent(5, 0, 1).unwrap().as_str(),
+        "n/a"
+    );
+
+    let err = FixStr::<4>::from_ratio_percent(7, 8, 1).unwrap_err();
+    assert_eq!((err.len, err.capacity), (5, 4));
+}
+
+#[cfg(not(feature = "panic-free"))]
+#[test]
+fn test_try_fill() {
+    let name: FixStr<8> = FixStr::new("Ada").unwrap();
+    let s = FixStr::<32>::try_fill("{} joined team {}", &[&name, &3]).unwrap();
+    assert_eq!(s.as_str(), "Ada joined team 3");
+
+    let s = FixStr::<32>::try_fill("{{{}}} {}%", &[&"x", &99.5]).unwrap();
+    assert_eq!(s.as_str(), "{x} 99.5%");
+    assert_eq!(FixStr::<8>::try_fill("", &[]).unwrap().as_str(), "");
+}
+
+#[cfg(not(feature = "panic-free"))]
+#[test]
+fn test_try_fill_errors() {
+    use fixstr::FillError;
+
+    assert_eq!(
+        FixStr::<32>::try_fill("{} and {}", &[&1]),
+        Err(FillError::ArgumentCount {
+            placeholders: 2,
+            arguments: 1
+        })
+    );
+    assert_eq!(
+        FixStr::<32>::try_fill("value: {0}", &[&1]),
+        Err(FillError::UnmatchedBrace { index: 7 })
+    );
+    assert_eq!(
+        FixStr::<32>::try_fill("oops }", &[]),
+        Err(FillError::UnmatchedBrace { index: 5 })
+    );
+
+    let err = FixStr::<4>::try_fill("{} wins", &[&"Ada"]).unwrap_err();
+    assert_eq!(err.to_string(), "String (len=8) exceeds capacity 4");
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "unicode-segmentation")]
+
+use fixstr::FixStr;
+
+#[test]
+fn test_graphemes() {
+    let s: FixStr<32> = FixStr::new("🇸🇪 noe\u{308}l").unwrap();
+    assert_eq!(
+        s.graphemes().collect::<Vec<_>>(),
+        ["🇸🇪", " ", "n", "o", "e\u{308}", "l"]
+    );
+    assert_eq!(s.graphemes().next_back(), Some("l"));
+}
+
+#[test]
+fn test_grapheme_len() {
+    let s: FixStr<32> = FixStr::new("👨‍👩‍👧 hi").unwrap();
+    assert_eq!(s.grapheme_len(), 4);
+    assert!(s.char_len() > s.grapheme_len());
+
+    assert_eq!(FixStr::<4>::default().grapheme_len(), 0);
+}
@@ -0,0 +1,69 @@
+use fixstr::FixStr;
+
+#[test]
+fn test_as_mut_str() {
+    let mut s: FixStr<16> = FixStr::new("Hello").unwrap();
+    s.as_mut_str().make_ascii_lowercase();
+    assert_eq!(s.as_str(), "hello");
+}
+
+#[test]
+fn test_bytes_mut_guard_keeps_valid_changes() {
+    let mut s: FixStr<16> = FixStr::new("abcd").unwrap();
+    {
+        let mut bytes = s.bytes_mut_guard();
+        bytes.swap(0, 3);
+        bytes.reverse();
+    }
+    assert_eq!(s.as_str(), "acbd");
+
+    let mut guard = s.bytes_mut_guard();
+    guard[0] = b'z';
+    assert!(guard.commit().is_ok());
+    assert_eq!(s.as_str(), "zcbd");
+}
+
+#[test]
+fn test_bytes_mut_guard_reverts_invalid_utf8() {
+    let mut s: FixStr<16> = FixStr::new("né").unwrap();
+    {
+        let mut bytes = s.bytes_mut_guard();
+        bytes.reverse();
+    }
+    assert_eq!(s.as_str(), "né");
+
+    let mut guard = s.bytes_mut_guard();
+    guard[1] = 0xFF;
+    assert!(guard.commit().is_err());
+    assert_eq!(s.as_str(), "né");
+}
+
+#[test]
+fn test_bytes_mut_guard_forget_keeps_original() {
+    let mut s: FixStr<16> = FixStr::new("né").unwrap();
+    let mut guard = s.bytes_mut_guard();
+    guard[0] = 0xFF;
+    std::mem::forget(guard);
+    assert_eq!(s.as_str(), "né");
+    assert!(std::str::from_utf8(s.as_bytes()).is_ok());
+}
+
+#[cfg(not(feature = "no-unsafe"))]
+#[test]
+fn test_spare_capacity_and_set_len() {
+    let mut s: FixStr<8> = FixStr::new("id:").unwrap();
+    let spare = s.spare_capacity_mut();
+    assert_eq!(spare.len(), 5);
+    spare[..3].copy_from_slice(b"42x");
+    assert_eq!(s.as_str(), "id:");
+
+    // SAFETY: the first 5 octets are "id:42", valid UTF-8 within capacity
+    unsafe { s.set_len(5) };
+    assert_eq!(s.as_str(), "id:42");
+    assert_eq!(s, FixStr::new("id:42").unwrap());
+
+    // SAFETY: shrinking to a char boundary of valid UTF-8
+    unsafe { s.set_len(2) };
+    assert_eq!(s.as_str(), "id");
+    assert_eq!(s.spare_capacity_mut(), [0; 6]);
+}
@@ -0,0 +1,20 @@
+use fixstr::{fixconcat, fnv1a, FixStr};
+
+#[test]
+fn test_fnv1a_known_values() {
+    assert_eq!(fnv1a(""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(fnv1a("a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(fnv1a("foobar"), 0x8594_4171_f739_67e8);
+}
+
+#[test]
+fn test_hash_fnv1a_matches_runtime() {
+    const LOGIN: FixStr<5> = fixconcat!("log", "in");
+    const LOGIN_HASH: u64 = fixconcat!("login").hash_fnv1a();
+
+    let runtime = FixStr::<16>::new("login").unwrap();
+    assert_eq!(runtime.hash_fnv1a(), LOGIN_HASH);
+    assert_eq!(fnv1a("login"), LOGIN_HASH);
+    assert_eq!(LOGIN.hash_fnv1a(), LOGIN_HASH);
+    assert_ne!(fnv1a("logout"), LOGIN_HASH);
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "http")]
+
+use fixstr::FixStr;
+use http::HeaderValue;
+
+#[test]
+fn test_from_header_value() {
+    let value = HeaderValue::from_static("req-1234");
+    let s: FixStr<16> = (&value).try_into().unwrap();
+    assert_eq!(s.as_str(), "req-1234");
+
+    assert!(FixStr::<4>::try_from(&value).is_err());
+
+    let opaque = HeaderValue::from_bytes(b"caf\xe9").unwrap();
+    assert!(FixStr::<16>::try_from(&opaque).is_err());
+}
+
+#[test]
+fn test_into_header_value() {
+    let s: FixStr<16> = FixStr::new("req-1234").unwrap();
+    let value = HeaderValue::try_from(s).unwrap();
+    assert_eq!(value, "req-1234");
+
+    let invalid: FixStr<16> = FixStr::new("line\nbreak").unwrap();
+    assert!(HeaderValue::try_from(invalid).is_err());
+}
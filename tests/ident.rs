@@ -0,0 +1,33 @@
+use fixstr::{FixStr, IdentFixStr, ValidationError};
+
+#[test]
+fn test_new() {
+    for ident in ["x", "_", "health_points", "Transform2D", "__init"] {
+        assert!(IdentFixStr::<16>::new(ident).is_some(), "{ident}");
+    }
+    for not_ident in ["", "2d", "max-hp", "name space", "naïve"] {
+        assert!(IdentFixStr::<16>::new(not_ident).is_none(), "{not_ident}");
+    }
+    assert!(IdentFixStr::<4>::new("velocity").is_none());
+}
+
+#[test]
+fn test_conversions() {
+    let fix: FixStr<16> = FixStr::new("velocity").unwrap();
+    let ident = IdentFixStr::from_fixstr(fix).unwrap();
+    assert_eq!(ident.as_str(), "velocity");
+    assert_eq!(format!("{ident:?}"), "IdentFixStr(\"velocity\")");
+    assert_eq!(FixStr::from(ident), fix);
+
+    let err = IdentFixStr::<16>::try_from("max-hp").unwrap_err();
+    assert_eq!(
+        err,
+        ValidationError::Invalid {
+            type_name: "IdentFixStr"
+        }
+    );
+    assert_eq!(err.to_string(), "String is not a valid IdentFixStr");
+
+    const SPEED: IdentFixStr<8> = IdentFixStr::new("speed").unwrap();
+    assert_eq!(SPEED.as_str(), "speed");
+}
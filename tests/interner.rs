@@ -0,0 +1,52 @@
+#![cfg(feature = "alloc")]
+
+use fixstr::{CapacityError, FixStr, FixStrInterner, InternError};
+
+#[test]
+fn test_intern_and_resolve() {
+    let mut interner: FixStrInterner<16> = FixStrInterner::new();
+    let sword = interner.intern_str("sword").unwrap();
+    let shield = interner.intern_str("shield").unwrap();
+    let sword_again = interner.try_intern(FixStr::new("sword").unwrap()).unwrap();
+
+    assert_eq!(sword, sword_again);
+    assert_ne!(sword, shield);
+    assert_eq!((sword.index(), shield.index()), (0, 1));
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.resolve(shield).unwrap().as_str(), "shield");
+}
+
+#[test]
+fn test_get_and_iter() {
+    let mut interner: FixStrInterner<8> = FixStrInterner::with_capacity(4);
+    assert!(interner.is_empty());
+    let bow = interner.intern_str("bow").unwrap();
+
+    assert_eq!(interner.get("bow"), Some(bow));
+    assert_eq!(interner.get("axe"), None);
+    assert_eq!(interner.get("much too long"), None);
+    assert_eq!(
+        interner.intern_str("much too long"),
+        Err(InternError::Capacity(CapacityError {
+            len: 13,
+            capacity: 8
+        }))
+    );
+
+    let entries: Vec<_> = interner
+        .iter()
+        .map(|(symbol, s)| (symbol, s.to_string()))
+        .collect();
+    assert_eq!(entries, [(bow, String::from("bow"))]);
+    assert_eq!(interner.iter().size_hint(), (1, Some(1)));
+    assert_eq!(FixStrInterner::<4>::new().resolve(bow), None);
+}
+
+#[cfg(not(feature = "panic-free"))]
+#[test]
+fn test_intern() {
+    let mut interner: FixStrInterner<8> = FixStrInterner::new();
+    let bow = interner.intern(FixStr::new("bow").unwrap());
+    assert_eq!(interner.intern(FixStr::new("bow").unwrap()), bow);
+    assert_eq!(interner.resolve(bow).unwrap().as_str(), "bow");
+}
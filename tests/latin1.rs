@@ -0,0 +1,65 @@
+#![cfg(feature = "latin1")]
+
+use fixstr::{CapacityError, EncodeError, FixStr};
+
+#[test]
+fn test_from_latin1() {
+    let s = FixStr::<16>::from_latin1(b"caf\xE9 \xA9").unwrap();
+    assert_eq!(s.as_str(), "café ©");
+
+    let s = FixStr::<4>::from_latin1(b"\x80\xFF").unwrap();
+    assert_eq!(s.as_str(), "\u{80}ÿ");
+
+    let error = FixStr::<4>::from_latin1(b"abc\xE9").unwrap_err();
+    assert_eq!((error.len, error.capacity), (5, 4));
+}
+
+#[test]
+fn test_from_windows1252() {
+    let s = FixStr::<32>::from_windows1252(b"\x93mod\x94 \x80 5 \x96 d\xE9mo").unwrap();
+    assert_eq!(s.as_str(), "“mod” € 5 – démo");
+
+    let s = FixStr::<8>::from_windows1252(b"\x81\x9F").unwrap();
+    assert_eq!(s.as_str(), "\u{81}Ÿ");
+
+    assert!(FixStr::<2>::from_windows1252(b"\x80").is_err());
+}
+
+#[test]
+fn test_encode_latin1_into() {
+    let s: FixStr<16> = FixStr::new("café ©").unwrap();
+    let mut buffer = [0u8; 8];
+    assert_eq!(s.encode_latin1_into(&mut buffer), Ok(6));
+    assert_eq!(&buffer[..6], b"caf\xE9 \xA9");
+
+    let mut short = [0u8; 5];
+    assert_eq!(
+        s.encode_latin1_into(&mut short),
+        Err(EncodeError::Capacity(CapacityError {
+            len: 6,
+            capacity: 5
+        }))
+    );
+    assert_eq!(short, [0; 5]);
+}
+
+#[test]
+fn test_encode_latin1_unmappable() {
+    let s: FixStr<16> = FixStr::new("5 € fee").unwrap();
+    let mut buffer = [0u8; 16];
+    let error = s.encode_latin1_into(&mut buffer).unwrap_err();
+    assert_eq!(
+        error,
+        EncodeError::Unmappable {
+            ch: '€', index: 2
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Character '€' at index 2 has no Latin-1 encoding"
+    );
+    assert_eq!(buffer, [0; 16]);
+
+    assert_eq!(s.encode_latin1_lossy_into(&mut buffer), Ok(7));
+    assert_eq!(&buffer[..7], b"5 ? fee");
+}
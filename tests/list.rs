@@ -0,0 +1,51 @@
+use fixstr::{FixStr, FixStrList};
+
+fn line(s: &str) -> FixStr<16> {
+    FixStr::new(s).unwrap()
+}
+
+fn lines<const K: usize>(list: &FixStrList<16, K>) -> Vec<&str> {
+    list.iter().map(FixStr::as_str).collect()
+}
+
+#[test]
+fn test_push_evicts_oldest() {
+    let mut history: FixStrList<16, 3> = FixStrList::new();
+    assert_eq!(history.push(line("hi")), None);
+    assert_eq!(history.push(line("gg")), None);
+    assert_eq!(history.push(line("brb")), None);
+    assert!(history.is_full());
+    assert_eq!(lines(&history), ["hi", "gg", "brb"]);
+
+    assert_eq!(history.push(line("back")), Some(line("hi")));
+    assert_eq!(history.push(line("go")), Some(line("gg")));
+    assert_eq!(lines(&history), ["brb", "back", "go"]);
+    assert_eq!(history.newest(), Some(&line("go")));
+    assert_eq!(history.len(), 3);
+}
+
+#[test]
+fn test_clear_and_zero_capacity() {
+    let mut history: FixStrList<16, 2> = FixStrList::default();
+    history.push(line("a"));
+    history.clear();
+    assert!(history.is_empty());
+    assert_eq!(history.newest(), None);
+
+    let mut none: FixStrList<16, 0> = FixStrList::new();
+    assert_eq!(none.push(line("a")), Some(line("a")));
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_eq_and_debug() {
+    let mut a: FixStrList<16, 2> = FixStrList::new();
+    let mut b: FixStrList<16, 2> = FixStrList::new();
+    for s in ["x", "y", "z"] {
+        a.push(line(s));
+    }
+    b.push(line("y"));
+    b.push(line("z"));
+    assert_eq!(a, b);
+    assert_eq!(format!("{a:?}"), r#"["y", "z"]"#);
+}
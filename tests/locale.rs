@@ -0,0 +1,50 @@
+use fixstr::{CapacityError, CountryCode, FixStr, LangTag, ValidationError};
+
+const SWEDEN: CountryCode = CountryCode::new("SE").unwrap();
+const BRAZILIAN_PORTUGUESE: LangTag<8> = LangTag::new("pt-BR").unwrap();
+
+#[test]
+fn test_country_code() {
+    assert_eq!(SWEDEN.as_str(), "SE");
+    assert_eq!(format!("{SWEDEN:?}"), "CountryCode(\"SE\")");
+    for invalid in ["se", "S", "SWE", "S1", "ÅS"] {
+        assert!(CountryCode::new(invalid).is_none(), "{invalid}");
+    }
+    assert_eq!(
+        CountryCode::try_from("se"),
+        Err(ValidationError::Invalid {
+            type_name: "CountryCode"
+        })
+    );
+    assert_eq!("SE".parse(), Ok(SWEDEN));
+}
+
+#[test]
+fn test_lang_tag() {
+    assert_eq!(BRAZILIAN_PORTUGUESE.as_str(), "pt-BR");
+    assert_eq!(BRAZILIAN_PORTUGUESE.language(), "pt");
+    for valid in ["en", "zh-Hant-TW", "es-419", "sgn-x-1"] {
+        assert!(LangTag::<16>::new(valid).is_some(), "{valid}");
+    }
+    for invalid in [
+        "",
+        "e",
+        "en-",
+        "-en",
+        "en--US",
+        "e1",
+        "en_US",
+        "en-abcdefghi",
+    ] {
+        assert!(LangTag::<16>::new(invalid).is_none(), "{invalid}");
+    }
+    assert!(LangTag::<4>::new("zh-Hant").is_none());
+    assert_eq!(
+        LangTag::<4>::try_new("zh-Hant"),
+        Err(ValidationError::Capacity(CapacityError {
+            len: 7,
+            capacity: 4
+        }))
+    );
+    assert_eq!(FixStr::from(BRAZILIAN_PORTUGUESE).as_str(), "pt-BR");
+}
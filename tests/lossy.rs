@@ -0,0 +1,34 @@
+use fixstr::{FixStr, LossyStats};
+
+#[test]
+fn test_clean_input() {
+    let (s, stats) = FixStr::<16>::from_utf8_lossy_stats("café".as_bytes());
+    assert_eq!(s.as_str(), "café");
+    assert_eq!(stats, LossyStats::default());
+    assert!(stats.is_clean());
+}
+
+#[test]
+fn test_replaced() {
+    let (s, stats) = FixStr::<16>::from_utf8_lossy_stats(b"a\xC3b\xF0\x9F\x98c");
+    assert_eq!(s.as_str(), "a\u{FFFD}b\u{FFFD}c");
+    assert_eq!(stats.replaced, 4);
+    assert_eq!(stats.dropped, 0);
+    assert!(!stats.is_clean());
+}
+
+#[test]
+fn test_dropped_at_char_boundary() {
+    let (s, stats) = FixStr::<4>::from_utf8_lossy_stats("abcé".as_bytes());
+    assert_eq!(s.as_str(), "abc");
+    assert_eq!(stats.replaced, 0);
+    assert_eq!(stats.dropped, 2);
+}
+
+#[test]
+fn test_replacement_that_does_not_fit() {
+    let (s, stats) = FixStr::<4>::from_utf8_lossy_stats(b"ab\xFFc");
+    assert_eq!(s.as_str(), "ab");
+    assert_eq!(stats.replaced, 0);
+    assert_eq!(stats.dropped, 2);
+}
@@ -0,0 +1,55 @@
+use fixstr::{FixStr, FixStrMap};
+
+fn key(s: &str) -> FixStr<16> {
+    FixStr::new(s).unwrap()
+}
+
+#[test]
+fn test_insert_get_remove() {
+    let mut headers: FixStrMap<16, u32, 4> = FixStrMap::new();
+    assert_eq!(headers.insert(key("content-length"), 42), Ok(None));
+    assert_eq!(headers.insert(key("ttl"), 8), Ok(None));
+    assert_eq!(headers.insert(key("ttl"), 16), Ok(Some(8)));
+
+    assert_eq!(headers.len(), 2);
+    assert_eq!(headers.get("ttl"), Some(&16));
+    assert_eq!(headers.get("missing"), None);
+    *headers.get_mut("content-length").unwrap() += 1;
+    assert_eq!(headers.get("content-length"), Some(&43));
+
+    assert_eq!(headers.remove("content-length"), Some(43));
+    assert_eq!(headers.remove("content-length"), None);
+    assert!(!headers.contains_key("content-length"));
+    assert!(headers.contains_key("ttl"));
+}
+
+#[test]
+fn test_full() {
+    let mut map: FixStrMap<16, String, 1> = FixStrMap::default();
+    map.insert(key("a"), String::from("1")).unwrap();
+    let (rejected_key, rejected_value) = map.insert(key("b"), String::from("2")).unwrap_err();
+    assert_eq!((rejected_key.as_str(), rejected_value.as_str()), ("b", "2"));
+    assert_eq!(
+        map.insert(key("a"), String::from("3")),
+        Ok(Some(String::from("1")))
+    );
+
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.capacity(), 1);
+}
+
+#[test]
+fn test_eq_and_debug() {
+    let mut a: FixStrMap<16, u8, 4> = FixStrMap::new();
+    a.insert(key("x"), 1).unwrap();
+    a.insert(key("y"), 2).unwrap();
+    let mut b = FixStrMap::new();
+    b.insert(key("y"), 2).unwrap();
+    b.insert(key("x"), 1).unwrap();
+
+    assert_eq!(a, b);
+    assert_eq!(format!("{a:?}"), r#"{"x": 1, "y": 2}"#);
+    let keys: Vec<&str> = a.iter().map(|(k, _)| k.as_str()).collect();
+    assert_eq!(keys, ["x", "y"]);
+}
@@ -0,0 +1,39 @@
+use fixstr::{FixStr, MaybeFixStr};
+
+fn unescape(token: &str) -> MaybeFixStr<'_, 16> {
+    if !token.contains('\\') {
+        return MaybeFixStr::Borrowed(token);
+    }
+    let unescaped = token.replace("\\n", "\n");
+    MaybeFixStr::Owned(FixStr::new(&unescaped).unwrap())
+}
+
+#[test]
+fn test_borrowed_and_owned() {
+    let plain = unescape("hello");
+    assert!(plain.is_borrowed());
+    assert_eq!(plain.as_str(), "hello");
+
+    let escaped = unescape("a\\nb");
+    assert!(!escaped.is_borrowed());
+    assert_eq!(escaped.as_str(), "a\nb");
+    assert_eq!(escaped.len(), 3);
+}
+
+#[test]
+fn test_into_owned() {
+    let borrowed: MaybeFixStr<'_, 4> = MaybeFixStr::from("abc");
+    assert_eq!(borrowed.into_owned().unwrap().as_str(), "abc");
+
+    let too_long: MaybeFixStr<'_, 4> = MaybeFixStr::from("abcdef");
+    assert!(too_long.into_owned().is_err());
+}
+
+#[test]
+fn test_eq_across_variants() {
+    let borrowed: MaybeFixStr<'_, 8> = "same".into();
+    let owned: MaybeFixStr<'_, 8> = FixStr::new("same").unwrap().into();
+    assert_eq!(borrowed, owned);
+    assert!(borrowed <= owned);
+    assert_eq!(format!("{owned:?}"), "MaybeFixStr(\"same\")");
+}
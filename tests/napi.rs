@@ -0,0 +1,16 @@
+#![cfg(all(feature = "napi", not(feature = "no-unsafe")))]
+
+use fixstr::FixStr;
+use napi::bindgen_prelude::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
+use napi::ValueType;
+
+// Converting requires a live Node.js environment, so natively this only checks that
+// FixStr satisfies the bounds used by `#[napi(object)]` fields and function signatures.
+
+fn assert_napi_field<T: FromNapiValue + ToNapiValue + ValidateNapiValue>() {}
+
+#[test]
+fn test_napi_traits() {
+    assert_napi_field::<FixStr<16>>();
+    assert_eq!(FixStr::<16>::value_type(), ValueType::String);
+}
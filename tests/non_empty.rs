@@ -0,0 +1,23 @@
+use fixstr::{FixStr, NonEmptyFixStr};
+
+#[test]
+fn test_new() {
+    let team: NonEmptyFixStr<16> = NonEmptyFixStr::new("Red").unwrap();
+    assert_eq!(team.as_str(), "Red");
+    assert_eq!(format!("{team:?}"), "NonEmptyFixStr(\"Red\")");
+
+    assert!(NonEmptyFixStr::<16>::new("").is_none());
+    assert!(NonEmptyFixStr::<2>::new("Red").is_none());
+}
+
+#[test]
+fn test_conversions() {
+    let fix: FixStr<8> = FixStr::new("Blue").unwrap();
+    let team = NonEmptyFixStr::try_from(fix).unwrap();
+    assert_eq!(FixStr::from(team), fix);
+    assert_eq!(team.into_inner(), fix);
+
+    let err = NonEmptyFixStr::<8>::try_from("").unwrap_err();
+    assert_eq!(err, "String is empty");
+    assert!(NonEmptyFixStr::<2>::try_from(String::from("Blue")).is_err());
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "unicode-normalization")]
+
+use fixstr::FixStr;
+
+#[test]
+fn test_nfc_and_nfd() {
+    let composed: FixStr<16> = FixStr::new_nfc("Zoe\u{0308}").unwrap();
+    let precomposed: FixStr<16> = FixStr::new_nfc("Zoë").unwrap();
+    assert_eq!(composed, precomposed);
+    assert_eq!(composed.len(), 4);
+
+    let decomposed = precomposed.normalize_nfd().unwrap();
+    assert_eq!(decomposed.as_str(), "Zoe\u{0308}");
+    assert_eq!(decomposed.normalize_nfc().unwrap(), precomposed);
+}
+
+#[test]
+fn test_normalize_exceeding_capacity() {
+    let s: FixStr<2> = FixStr::new("ë").unwrap();
+    let err = s.normalize_nfd().unwrap_err();
+    assert_eq!((err.len, err.capacity), (3, 2));
+    assert!(FixStr::<2>::new_nfc("e\u{0308}").is_ok());
+}
@@ -0,0 +1,676 @@
+//! Link-time proof that the allocation-free API cannot panic.
+//!
+//! `no_panic` only works with optimizations, so run with
+//! `cargo test --release --features "panic-free edit-distance latin1 punycode"`. The release
+//! profile uses one codegen unit and LTO so calls into the crate can be seen through.
+//!
+//! Every inherent fn left by `panic-free` has a wrapper here, except those behind `alloc` and
+//! the optional Unicode dependencies, whose allocation and table lookups keep panic paths.
+//!
+//! Skipped with `no-unsafe`, where `as_str` goes through `str::from_utf8`, whose
+//! validation keeps bounds-check panics the optimizer cannot prove unreachable.
+#![cfg(all(
+    feature = "panic-free",
+    not(feature = "no-unsafe"),
+    not(debug_assertions)
+))]
+
+use std::cmp::Ordering;
+use std::hint::black_box;
+use std::time::Duration;
+
+#[cfg(feature = "latin1")]
+use fixstr::EncodeError;
+use fixstr::{
+    Align16, AlignedFixStr, AtomicFixStr, CapacityError, CaselessFixStr, CountryCode,
+    DurationStyle, FixAsciiStr, FixByteStr, FixCString, FixStr, FixStrList, FixStrMap, FixStrSet,
+    IdentFixStr, LangTag, LossyStats, MaybeFixStr, NonEmptyFixStr, Tokenizer, TooLongError,
+    ValidationError, VersionFixStr, WideFixStr,
+};
+use no_panic::no_panic;
+
+#[no_panic]
+fn new(s: &str) -> Option<FixStr<16>> {
+    FixStr::new(s)
+}
+
+#[no_panic]
+fn try_new(s: &str) -> Result<FixStr<300>, CapacityError> {
+    FixStr::try_new(s)
+}
+
+#[no_panic]
+fn inspect(s: &FixStr<16>) -> (usize, usize, usize, bool, usize) {
+    (
+        s.as_str().len(),
+        s.as_bytes().len(),
+        s.char_len(),
+        s.is_empty(),
+        s.capacity(),
+    )
+}
+
+#[no_panic]
+fn resize(s: &FixStr<16>) -> Result<FixStr<4>, CapacityError> {
+    s.resize()
+}
+
+#[no_panic]
+fn try_new_or_prefix(s: &str) -> Result<FixStr<4>, TooLongError<4>> {
+    FixStr::try_new_or_prefix(s)
+}
+
+#[no_panic]
+fn try_from_array_all(strs: [&str; 3]) -> Result<[FixStr<4>; 3], (usize, CapacityError)> {
+    FixStr::try_from_array_all(strs)
+}
+
+#[no_panic]
+fn from_utf8_lossy_stats(bytes: &[u8]) -> (FixStr<16>, LossyStats) {
+    FixStr::from_utf8_lossy_stats(bytes)
+}
+
+#[no_panic]
+fn try_to_uppercase(s: &FixStr<16>) -> Result<FixStr<16>, CapacityError> {
+    s.try_to_uppercase()
+}
+
+#[no_panic]
+fn try_to_lowercase(s: &FixStr<16>) -> Result<FixStr<16>, CapacityError> {
+    s.try_to_lowercase()
+}
+
+#[no_panic]
+fn pad_start(s: &FixStr<16>, width: usize, fill: char) -> Result<FixStr<16>, CapacityError> {
+    s.pad_start(width, fill)
+}
+
+#[no_panic]
+fn pad_end(s: &FixStr<16>, width: usize, fill: char) -> Result<FixStr<16>, CapacityError> {
+    s.pad_end(width, fill)
+}
+
+#[no_panic]
+fn center(s: &FixStr<16>, width: usize, fill: char) -> Result<FixStr<16>, CapacityError> {
+    s.center(width, fill)
+}
+
+#[no_panic]
+fn escape_default(s: &FixStr<16>) -> Result<FixStr<48>, CapacityError> {
+    s.escape_default()
+}
+
+#[no_panic]
+fn strip_ansi(s: &FixStr<16>) -> FixStr<16> {
+    s.strip_ansi()
+}
+
+#[no_panic]
+fn trim(s: &FixStr<16>) -> (FixStr<16>, FixStr<16>, FixStr<16>) {
+    (s.trim(), s.trim_start(), s.trim_end())
+}
+
+#[no_panic]
+fn search(s: &FixStr<16>, needle: &str) -> (Option<usize>, Option<FixStr<16>>, usize) {
+    (
+        s.find(needle),
+        s.strip_prefix(needle),
+        s.count_matches(needle),
+    )
+}
+
+#[no_panic]
+fn split_once_byte(s: &FixStr<16>, byte: u8) -> Option<(&str, &str)> {
+    s.split_once_byte(byte)
+}
+
+#[no_panic]
+fn lines_fix(s: &FixStr<16>) -> (Option<FixStr<16>>, Option<FixStr<16>>) {
+    (s.lines_fix().next(), s.lines_fix().next_back())
+}
+
+#[no_panic]
+fn tokenize(input: &str) -> (Option<Result<FixStr<4>, CapacityError>>, usize) {
+    let mut tokens = Tokenizer::<4>::with_delimiters(input, &[',', ';']);
+    (tokens.next(), Tokenizer::<4>::new(input).count())
+}
+
+#[no_panic]
+fn compare(s: &FixStr<16>, other: &FixStr<16>) -> (bool, Ordering, u64, u64, bool) {
+    (
+        s.const_eq(other),
+        s.const_cmp(other),
+        s.hash_fnv1a(),
+        fixstr::fnv1a(other.as_str()),
+        s.ct_eq(other),
+    )
+}
+
+#[no_panic]
+fn ascii_case(s: &FixStr<16>, other: &str) -> (FixStr<16>, FixStr<16>, bool, Ordering, FixStr<16>) {
+    let mut in_place = *s;
+    in_place.make_ascii_uppercase();
+    in_place.make_ascii_lowercase();
+    (
+        s.to_ascii_uppercase(),
+        s.to_ascii_lowercase(),
+        s.eq_ignore_ascii_case(other),
+        s.cmp_ignore_ascii_case(other),
+        in_place,
+    )
+}
+
+#[no_panic]
+fn capitalize(s: &FixStr<16>) -> (FixStr<16>, FixStr<16>) {
+    (s.capitalize(), s.to_title_case_ascii())
+}
+
+#[no_panic]
+fn chars(s: &FixStr<16>, index: usize) -> (usize, usize, Option<char>, Option<char>, usize) {
+    (
+        s.chars().count() + s.char_indices().count(),
+        s.bytes().count(),
+        s.char_at(index),
+        s.nth_char(index),
+        s.floor_char_boundary(index) + s.ceil_char_boundary(index),
+    )
+}
+
+#[no_panic]
+fn char_classes(s: &FixStr<16>, index: usize) -> (bool, bool, bool, bool, bool) {
+    (
+        s.is_char_boundary(index),
+        s.all_chars(|c| c != '-'),
+        s.is_alphanumeric(),
+        s.is_numeric(),
+        s.is_alphabetic(),
+    )
+}
+
+#[no_panic]
+fn search_char(s: &FixStr<16>, c: char) -> (Option<usize>, Option<usize>, bool, bool, bool) {
+    (
+        s.find(c),
+        s.rfind(c),
+        s.contains(c),
+        s.starts_with(c),
+        s.ends_with(c),
+    )
+}
+
+#[no_panic]
+fn search_str(s: &FixStr<16>, needle: &str) -> (Option<usize>, Option<FixStr<16>>, usize) {
+    (
+        s.rfind(needle),
+        s.strip_suffix(needle),
+        s.matches(needle).count() + s.match_indices(needle).count(),
+    )
+}
+
+#[no_panic]
+fn find_byte(s: &FixStr<16>, byte: u8) -> (Option<usize>, Option<usize>) {
+    (s.find_byte(byte), s.rfind_byte(byte))
+}
+
+#[no_panic]
+fn split_fix(s: &FixStr<16>, needle: &str) -> (Option<FixStr<16>>, usize) {
+    (s.split_fix(needle).next(), s.split_fix(needle).count())
+}
+
+#[no_panic]
+fn split_array(s: &FixStr<16>, c: char) -> Option<[&str; 2]> {
+    s.split_array(c)
+}
+
+#[no_panic]
+fn split_whitespace(s: &FixStr<16>) -> (usize, Option<FixStr<16>>) {
+    (
+        s.split_whitespace().count(),
+        s.split_whitespace_fix().next_back(),
+    )
+}
+
+#[no_panic]
+fn lines(s: &FixStr<16>) -> usize {
+    s.lines().count()
+}
+
+#[no_panic]
+fn matches_glob(s: &FixStr<16>, pattern: &str) -> bool {
+    s.matches_glob(pattern)
+}
+
+#[no_panic]
+fn trim_variants(s: &FixStr<16>) -> (FixStr<16>, FixStr<16>, FixStr<16>, FixStr<16>) {
+    let mut trimmed = *s;
+    trimmed.trim_in_place();
+    (s.trim_start(), s.trim_end(), s.trim_matches('-'), trimmed)
+}
+
+#[no_panic]
+fn reversed(s: &FixStr<16>) -> FixStr<16> {
+    s.reversed()
+}
+
+#[no_panic]
+fn encode_utf16(s: &FixStr<16>, buffer: &mut [u16]) -> (usize, Result<usize, CapacityError>) {
+    (s.encode_utf16().count(), s.encode_utf16_into(buffer))
+}
+
+#[no_panic]
+fn guard(mut s: FixStr<16>, byte: u8) -> (Result<(), std::str::Utf8Error>, usize) {
+    s.as_mut_str().make_ascii_uppercase();
+    let spare = s.spare_capacity_mut().len();
+    let mut guard = s.bytes_mut_guard();
+    if let Some(first) = guard.first_mut() {
+        *first = byte;
+    }
+    (guard.commit(), spare)
+}
+
+#[no_panic]
+fn wrappers(s: &str) -> (Option<FixStr<16>>, Option<FixStr<16>>, FixStr<16>, bool) {
+    let aligned = AlignedFixStr::<16, Align16>::new(s).map(AlignedFixStr::into_inner);
+    let non_empty = NonEmptyFixStr::<16>::new(s).map(NonEmptyFixStr::into_inner);
+    let caseless = CaselessFixStr(aligned.unwrap_or_default()).into_inner();
+    let maybe = MaybeFixStr::<16>::Borrowed(s);
+    (
+        aligned,
+        non_empty,
+        caseless,
+        maybe.is_borrowed() && maybe.as_str() == s,
+    )
+}
+
+#[no_panic]
+fn maybe_into_owned(s: &str) -> Result<FixStr<16>, CapacityError> {
+    MaybeFixStr::Borrowed(s).into_owned()
+}
+
+#[no_panic]
+fn ascii_str(s: &str, index: usize) -> Option<(Option<u8>, FixAsciiStr<16>, FixAsciiStr<16>)> {
+    let mut ascii = FixAsciiStr::<16>::new(s)?;
+    ascii.make_ascii_uppercase();
+    ascii.make_ascii_lowercase();
+    Some((
+        ascii.byte_at(index),
+        ascii.to_ascii_uppercase(),
+        ascii.to_ascii_lowercase(),
+    ))
+}
+
+#[no_panic]
+fn byte_str(bytes: &[u8]) -> Option<(usize, bool, usize, bool)> {
+    let byte_str = FixByteStr::<16>::new(bytes)?;
+    Some((
+        byte_str.as_bytes().len() + byte_str.len(),
+        byte_str.is_empty(),
+        byte_str.capacity(),
+        byte_str.to_str().is_ok(),
+    ))
+}
+
+#[no_panic]
+fn c_string(s: &str) -> Option<(usize, usize, bool, usize, bool)> {
+    let c_string = FixCString::<16>::new(s)?;
+    Some((
+        c_string.as_c_str().count_bytes(),
+        c_string.as_str().len() + c_string.len(),
+        c_string.is_empty(),
+        c_string.capacity(),
+        c_string.as_ptr().is_null(),
+    ))
+}
+
+#[no_panic]
+fn wide(s: &str) -> Result<(usize, bool, usize), CapacityError> {
+    let wide = WideFixStr::<300>::try_new(s)?;
+    let narrow = WideFixStr::<300>::new(wide.as_str()).unwrap_or_default();
+    Ok((
+        narrow.as_bytes().len() + wide.len(),
+        wide.is_empty(),
+        wide.capacity(),
+    ))
+}
+
+#[no_panic]
+fn atomic(s: FixStr<16>, next: FixStr<16>) -> FixStr<16> {
+    let cell = AtomicFixStr::new(s);
+    cell.store(next);
+    cell.load()
+}
+
+#[no_panic]
+fn list(a: FixStr<16>, b: FixStr<16>) -> (Option<FixStr<16>>, usize, bool, bool, usize) {
+    let mut list: FixStrList<16, 2> = FixStrList::new();
+    list.push(a);
+    list.push(b);
+    let evicted = list.push(a);
+    let count = list.iter().count() + list.newest().map_or(0, FixStr::len);
+    let full = list.is_full();
+    list.clear();
+    (
+        evicted,
+        count,
+        full,
+        list.is_empty(),
+        list.len() + list.capacity(),
+    )
+}
+
+#[no_panic]
+fn map(key: FixStr<8>, other: &str) -> (Option<u32>, bool, Option<u32>, usize, bool) {
+    let mut map: FixStrMap<8, u32, 2> = FixStrMap::new();
+    let _ = map.insert(key, 1);
+    if let Some(value) = map.get_mut(key.as_str()) {
+        *value += 1;
+    }
+    let found = map.get(other).copied();
+    let contained = map.contains_key(other);
+    let removed = map.remove(key.as_str());
+    let count = map.iter().count() + map.len() + map.capacity();
+    map.clear();
+    (found, contained, removed, count, map.is_empty())
+}
+
+#[no_panic]
+fn set(s: FixStr<8>, other: &str) -> (Result<bool, FixStr<8>>, bool, usize, bool) {
+    let mut set: FixStrSet<8, 2> = FixStrSet::new();
+    let inserted = set.insert(s);
+    let contained = set.contains(other);
+    let count = set.as_slice().len() + set.iter().count() + set.capacity();
+    set.clear();
+    (inserted, contained, count, set.is_empty())
+}
+
+#[no_panic]
+fn tokenizer_remainder(input: &str) -> usize {
+    let mut tokens = Tokenizer::<4>::new(input);
+    let _ = tokens.next();
+    tokens.remainder().len()
+}
+
+#[no_panic]
+fn ident(
+    s: &str,
+) -> (
+    Option<IdentFixStr<16>>,
+    Result<IdentFixStr<16>, ValidationError>,
+) {
+    (
+        FixStr::new(s).and_then(IdentFixStr::from_fixstr),
+        IdentFixStr::try_new(s),
+    )
+}
+
+#[no_panic]
+fn version(s: &str, other: &str) -> Option<(Ordering, usize)> {
+    let version = VersionFixStr::<16>::new(s)?;
+    let other = FixStr::new(other).and_then(VersionFixStr::<16>::from_fixstr)?;
+    Some((version.cmp(&other), version.components().count()))
+}
+
+#[no_panic]
+fn country_code(s: &str) -> Result<FixStr<2>, ValidationError> {
+    CountryCode::try_new(s).map(|code| *code.as_fixstr())
+}
+
+#[cfg(feature = "latin1")]
+#[no_panic]
+fn encode_latin1(
+    s: &FixStr<16>,
+    buffer: &mut [u8],
+) -> (Result<usize, EncodeError>, Result<usize, CapacityError>) {
+    (
+        s.encode_latin1_into(buffer),
+        s.encode_latin1_lossy_into(buffer),
+    )
+}
+
+#[cfg(feature = "latin1")]
+#[no_panic]
+fn from_windows1252(bytes: &[u8]) -> Result<FixStr<16>, CapacityError> {
+    FixStr::from_windows1252(bytes)
+}
+
+#[cfg(feature = "edit-distance")]
+#[no_panic]
+fn edit_distance(s: &FixStr<16>, other: &str) -> (usize, f64) {
+    (s.edit_distance(other), s.similarity(other))
+}
+
+#[no_panic]
+fn from_int_padded(value: i64, width: usize) -> Result<FixStr<16>, CapacityError> {
+    FixStr::from_int_padded(value, width)
+}
+
+#[no_panic]
+fn from_duration(duration: Duration, style: DurationStyle) -> Result<FixStr<16>, CapacityError> {
+    FixStr::from_duration(duration, style)
+}
+
+#[no_panic]
+fn from_bytes_size(bytes: u64) -> Result<FixStr<16>, CapacityError> {
+    FixStr::from_bytes_size(bytes)
+}
+
+#[no_panic]
+fn set_remove(mut set: FixStrSet<8, 4>, s: &str) -> (bool, usize) {
+    (set.remove(s), set.len())
+}
+
+#[no_panic]
+fn lang_tag_language(s: &str) -> Option<FixStr<8>> {
+    LangTag::<16>::new(s).and_then(|tag| FixStr::new(tag.language()))
+}
+
+#[cfg(feature = "latin1")]
+#[no_panic]
+fn from_latin1(bytes: &[u8]) -> Result<FixStr<16>, CapacityError> {
+    FixStr::from_latin1(bytes)
+}
+
+#[cfg(feature = "punycode")]
+#[no_panic]
+fn to_punycode(s: &FixStr<16>) -> Result<FixStr<32>, CapacityError> {
+    s.to_punycode()
+}
+
+#[cfg(feature = "punycode")]
+#[no_panic]
+fn from_punycode(s: &str) -> Result<FixStr<16>, fixstr::PunycodeError> {
+    FixStr::from_punycode(s)
+}
+
+// Inputs go through `black_box` so they are not folded into the checked functions
+
+#[test]
+fn test_core_api_is_panic_free() {
+    let s = new(black_box("héllo")).unwrap();
+    assert_eq!(inspect(&s), (6, 6, 5, false, 16));
+    assert!(try_new(black_box(&"x".repeat(256))).is_err());
+    assert!(resize(black_box(&s)).is_err());
+    assert!(try_new_or_prefix(black_box("sword")).is_err());
+    let strs = black_box(["a", "b", "toolong"]);
+    assert_eq!(try_from_array_all(strs).unwrap_err().0, 2);
+    assert_eq!(from_utf8_lossy_stats(black_box(b"ok\xFF")).1.replaced, 1);
+}
+
+#[test]
+fn test_transforms_are_panic_free() {
+    let s = new(black_box(" héllo ")).unwrap();
+    assert_eq!(try_to_uppercase(&s).unwrap().as_str(), " HÉLLO ");
+    assert_eq!(try_to_lowercase(&s).unwrap().as_str(), " héllo ");
+    assert!(pad_start(&s, black_box(usize::MAX), '*').is_err());
+    assert_eq!(
+        pad_end(&s, black_box(9), '.').unwrap().as_str(),
+        " héllo .."
+    );
+    assert_eq!(
+        center(&s, black_box(10), '-').unwrap().as_str(),
+        "- héllo --"
+    );
+    assert_eq!(escape_default(&s).unwrap().as_str(), " h\\u{e9}llo ");
+    assert_eq!(trim(&s).0.as_str(), "héllo");
+    let colored = new(black_box("\x1b[1;31mhi\x1b[0m")).unwrap();
+    assert_eq!(strip_ansi(&colored).as_str(), "hi");
+    assert_eq!(search(&s, black_box("llo")).0, Some(4));
+    assert_eq!(split_once_byte(&s, black_box(b'l')), Some((" hé", "lo ")));
+    let lines = lines_fix(&new(black_box("a\r\nb")).unwrap());
+    assert_eq!(
+        (lines.0.unwrap().as_str(), lines.1.unwrap().as_str()),
+        ("a", "b")
+    );
+    let (first, count) = tokenize(black_box("give;;sword, 2"));
+    assert_eq!(first.unwrap().unwrap().as_str(), "give");
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_formatters_are_panic_free() {
+    assert_eq!(
+        from_int_padded(black_box(-42), black_box(5))
+            .unwrap()
+            .as_str(),
+        "-0042"
+    );
+    assert!(from_int_padded(black_box(1), black_box(usize::MAX)).is_err());
+    let duration = black_box(Duration::from_secs(192));
+    assert_eq!(
+        from_duration(duration, DurationStyle::Clock)
+            .unwrap()
+            .as_str(),
+        "00:03:12"
+    );
+    assert_eq!(
+        from_duration(duration, DurationStyle::Compact)
+            .unwrap()
+            .as_str(),
+        "3m 12s"
+    );
+    assert_eq!(
+        from_bytes_size(black_box(1_258_291)).unwrap().as_str(),
+        "1.2 MiB"
+    );
+}
+
+#[test]
+fn test_collections_are_panic_free() {
+    let mut set: FixStrSet<8, 4> = FixStrSet::new();
+    set.insert(FixStr::new("a").unwrap()).unwrap();
+    set.insert(FixStr::new("b").unwrap()).unwrap();
+    assert_eq!(set_remove(set, black_box("a")), (true, 1));
+    assert_eq!(set_remove(set, black_box("c")), (false, 2));
+}
+
+#[test]
+fn test_validated_types_are_panic_free() {
+    assert_eq!(
+        lang_tag_language(black_box("pt-BR")).unwrap().as_str(),
+        "pt"
+    );
+    assert!(lang_tag_language(black_box("p")).is_none());
+}
+
+#[test]
+fn test_queries_are_panic_free() {
+    let s = new(black_box("héllo-wörld")).unwrap();
+    let other = new(black_box("HÉLLO-WÖRLD")).unwrap();
+    assert_eq!(compare(&s, &other).1, Ordering::Greater);
+    assert!(ascii_case(&s, black_box("HéLLO-WöRLD")).2);
+    assert_eq!(capitalize(&s).1.as_str(), "Héllo-Wörld");
+    assert_eq!(chars(&s, black_box(1)).2, Some('é'));
+    assert!(!char_classes(&s, black_box(2)).0);
+    assert_eq!(
+        search_char(&s, black_box('l')),
+        (Some(3), Some(11), true, false, false)
+    );
+    assert_eq!(search_str(&s, black_box("ld")).0, Some(11));
+    assert_eq!(find_byte(&s, black_box(b'-')), (Some(6), Some(6)));
+    assert_eq!(split_fix(&s, black_box("-")).1, 2);
+    assert_eq!(split_array(&s, black_box('-')), Some(["héllo", "wörld"]));
+    assert_eq!(split_whitespace(&new(black_box(" a b ")).unwrap()).0, 2);
+    assert_eq!(lines(&new(black_box("a\nb")).unwrap()), 2);
+    assert!(matches_glob(&s, black_box("h*-?örld")));
+}
+
+#[test]
+fn test_edits_are_panic_free() {
+    let s = new(black_box("-héllo-")).unwrap();
+    assert_eq!(trim_variants(&s).2.as_str(), "héllo");
+    assert_eq!(reversed(&s).as_str(), "-olléh-");
+    assert_eq!(
+        encode_utf16(&s, &mut [0; 4]).1,
+        Err(CapacityError {
+            len: 7,
+            capacity: 4
+        })
+    );
+    assert!(guard(s, black_box(0xFF)).0.is_err());
+}
+
+#[test]
+fn test_wrapper_types_are_panic_free() {
+    let s = black_box("héllo");
+    assert!(wrappers(s).3);
+    assert!(maybe_into_owned(black_box("much too long for this")).is_err());
+    assert_eq!(
+        ascii_str(black_box("Abc"), black_box(1)).unwrap().0,
+        Some(b'b')
+    );
+    assert_eq!(byte_str(black_box(b"ok\xFF")).unwrap().0, 6);
+    assert_eq!(c_string(s).unwrap().0, 6);
+    assert_eq!(wide(s).unwrap().0, 12);
+    let next = new(black_box("next")).unwrap();
+    assert_eq!(atomic(new(s).unwrap(), next), next);
+}
+
+#[test]
+fn test_collection_methods_are_panic_free() {
+    let a = new(black_box("a")).unwrap();
+    let b = new(black_box("b")).unwrap();
+    assert_eq!(list(a, b).0, Some(a));
+    let key = FixStr::new(black_box("hp")).unwrap();
+    assert_eq!(map(key, black_box("hp")), (Some(2), true, Some(2), 2, true));
+    assert!(!set(key, black_box("mp")).1);
+    assert_eq!(tokenizer_remainder(black_box("give sword")), 5);
+}
+
+#[test]
+fn test_validated_type_methods_are_panic_free() {
+    assert!(ident(black_box("speed")).1.is_ok());
+    assert_eq!(
+        version(black_box("1.10"), black_box("1.9")),
+        Some((Ordering::Greater, 2))
+    );
+    assert!(country_code(black_box("se")).is_err());
+}
+
+#[cfg(feature = "edit-distance")]
+#[test]
+fn test_edit_distance_is_panic_free() {
+    let s = new(black_box("kitten")).unwrap();
+    assert_eq!(edit_distance(&s, black_box("sitting")).0, 3);
+}
+
+#[cfg(feature = "latin1")]
+#[test]
+fn test_latin1_is_panic_free() {
+    assert_eq!(from_latin1(black_box(b"caf\xE9")).unwrap().as_str(), "café");
+    assert_eq!(from_windows1252(black_box(b"\x80")).unwrap().as_str(), "€");
+    let s = new(black_box("café€")).unwrap();
+    let (strict, lossy) = encode_latin1(&s, &mut [0; 8]);
+    assert!(strict.is_err());
+    assert_eq!(lossy, Ok(5));
+}
+
+#[cfg(feature = "punycode")]
+#[test]
+fn test_punycode_is_panic_free() {
+    let encoded = to_punycode(&new(black_box("bücher.example")).unwrap()).unwrap();
+    assert_eq!(encoded.as_str(), "xn--bcher-kva.example");
+    assert_eq!(
+        from_punycode(encoded.as_str()).unwrap().as_str(),
+        "bücher.example"
+    );
+}
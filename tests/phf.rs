@@ -0,0 +1,43 @@
+#![cfg(feature = "phf")]
+
+use fixstr::FixStr;
+use phf::Map;
+
+type Handler = fn() -> &'static str;
+
+fn help() -> &'static str {
+    "help"
+}
+
+fn quit() -> &'static str {
+    "quit"
+}
+
+/// Builds the map the same way `phf_codegen` lays it out in generated code.
+fn commands() -> Map<FixStr<8>, Handler> {
+    let keys = [FixStr::new("help").unwrap(), FixStr::new("quit").unwrap()];
+    let handlers: [Handler; 2] = [help, quit];
+    let state = phf_generator::generate_hash(&keys);
+    let entries: Vec<_> = state.map.iter().map(|&i| (keys[i], handlers[i])).collect();
+    Map {
+        key: state.key,
+        disps: Vec::leak(state.disps),
+        entries: Vec::leak(entries),
+    }
+}
+
+#[test]
+fn test_lookup_with_str() {
+    let map = commands();
+    assert_eq!(map.get("help").map(|handler| handler()), Some("help"));
+    assert_eq!(map.get("quit").map(|handler| handler()), Some("quit"));
+    assert!(map.get("load").is_none());
+}
+
+#[test]
+fn test_lookup_with_fixstr() {
+    let map = commands();
+    let key: FixStr<8> = FixStr::new("quit").unwrap();
+    assert!(map.contains_key(&key));
+    assert_eq!(map.get_key(&key), Some(&key));
+}
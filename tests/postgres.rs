@@ -0,0 +1,30 @@
+#![cfg(feature = "postgres")]
+
+use bytes::BytesMut;
+use fixstr::{CapacityError, FixStr};
+use postgres_types::{FromSql, ToSql, Type};
+
+#[test]
+fn test_to_sql_as_text() {
+    let s: FixStr<8> = FixStr::new("alice").unwrap();
+    let mut out = BytesMut::new();
+    s.to_sql_checked(&Type::VARCHAR, &mut out).unwrap();
+    assert_eq!(&out[..], b"alice");
+
+    assert!(s.to_sql_checked(&Type::INT4, &mut out).is_err());
+}
+
+#[test]
+fn test_from_sql_checks_capacity() {
+    let s = FixStr::<8>::from_sql(&Type::TEXT, b"alice").unwrap();
+    assert_eq!(s.as_str(), "alice");
+
+    let err = FixStr::<4>::from_sql(&Type::TEXT, b"alice").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<CapacityError>(),
+        Some(&CapacityError {
+            len: 5,
+            capacity: 4
+        })
+    );
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "punycode")]
+
+use fixstr::{FixStr, PunycodeError};
+
+fn encode(s: &str) -> String {
+    let s: FixStr<64> = FixStr::new(s).unwrap();
+    s.to_punycode::<64>().unwrap().into()
+}
+
+fn decode(s: &str) -> String {
+    FixStr::<64>::from_punycode(s).unwrap().into()
+}
+
+const SAMPLES: [(&str, &str); 5] = [
+    ("münchen", "xn--mnchen-3ya"),
+    ("bücher.example", "xn--bcher-kva.example"),
+    ("例え.テスト", "xn--r8jz45g.xn--zckzah"),
+    ("他们为什么不说中文", "xn--ihqwcrb4cv8a8dqg056pqjye"),
+    ("plain.example", "plain.example"),
+];
+
+#[test]
+fn test_to_punycode() {
+    for (unicode, ascii) in SAMPLES {
+        assert_eq!(encode(unicode), ascii);
+    }
+}
+
+#[test]
+fn test_from_punycode() {
+    for (unicode, ascii) in SAMPLES {
+        assert_eq!(decode(ascii), unicode);
+    }
+    assert_eq!(decode("XN--mnchen-3ya.de"), "münchen.de");
+}
+
+#[test]
+fn test_capacity() {
+    let s: FixStr<8> = FixStr::new("münchen").unwrap();
+    assert!(s.to_punycode::<8>().is_err());
+    assert!(matches!(
+        FixStr::<4>::from_punycode("xn--mnchen-3ya"),
+        Err(PunycodeError::Capacity(_))
+    ));
+}
+
+#[test]
+fn test_invalid_label() {
+    assert_eq!(
+        FixStr::<32>::from_punycode("ok.xn--a!b"),
+        Err(PunycodeError::InvalidLabel { index: 3 })
+    );
+    assert!(FixStr::<32>::from_punycode("xn--mnchen-3y").is_err());
+    assert!(FixStr::<32>::from_punycode("xn--999999999999").is_err());
+}
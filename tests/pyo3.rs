@@ -0,0 +1,30 @@
+#![cfg(feature = "pyo3")]
+
+use fixstr::{FixStr, PyCapacityExceededError};
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+
+#[test]
+fn test_into_python_str() {
+    Python::initialize();
+    Python::attach(|py| {
+        let s: FixStr<8> = FixStr::new("hello").unwrap();
+        let obj = s.into_pyobject(py).unwrap();
+        assert_eq!(obj.to_str().unwrap(), "hello");
+    });
+}
+
+#[test]
+fn test_extract_from_python_str() {
+    Python::initialize();
+    Python::attach(|py| {
+        let s: FixStr<8> = PyString::new(py, "hello").extract().unwrap();
+        assert_eq!(s.as_str(), "hello");
+
+        let err = PyString::new(py, "hello")
+            .extract::<FixStr<4>>()
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyCapacityExceededError>(py));
+        assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    });
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "redis")]
+
+use fixstr::FixStr;
+use redis::{FromRedisValue, ToRedisArgs, Value};
+
+#[test]
+fn test_to_redis_args() {
+    let s: FixStr<16> = FixStr::new("session:42").unwrap();
+    assert_eq!(s.to_redis_args(), vec![b"session:42".to_vec()]);
+}
+
+#[test]
+fn test_from_redis_value() {
+    let value = Value::BulkString(b"online".to_vec());
+    let s = FixStr::<8>::from_redis_value_ref(&value).unwrap();
+    assert_eq!(s.as_str(), "online");
+
+    assert!(FixStr::<4>::from_redis_value(value).is_err());
+    assert!(FixStr::<8>::from_redis_value(Value::Nil).is_err());
+}
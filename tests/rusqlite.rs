@@ -0,0 +1,47 @@
+#![cfg(feature = "rusqlite")]
+
+use fixstr::{CapacityError, FixStr};
+use rusqlite::{Connection, Error};
+
+fn connection() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE saves (name TEXT NOT NULL)", ())
+        .unwrap();
+    conn
+}
+
+#[test]
+fn test_bind_and_read() {
+    let conn = connection();
+    let name: FixStr<8> = FixStr::new("slot1").unwrap();
+    conn.execute("INSERT INTO saves (name) VALUES (?1)", [name])
+        .unwrap();
+
+    let loaded: FixStr<8> = conn
+        .query_row("SELECT name FROM saves", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(loaded, name);
+}
+
+#[test]
+fn test_read_reports_capacity_error() {
+    let conn = connection();
+    conn.execute("INSERT INTO saves (name) VALUES ('autosave')", ())
+        .unwrap();
+
+    let err = conn
+        .query_row("SELECT name FROM saves", [], |row| {
+            row.get::<_, FixStr<4>>(0)
+        })
+        .unwrap_err();
+    let Error::FromSqlConversionFailure(_, _, source) = err else {
+        panic!("unexpected error {err:?}");
+    };
+    assert_eq!(
+        source.downcast_ref::<CapacityError>(),
+        Some(&CapacityError {
+            len: 8,
+            capacity: 4
+        })
+    );
+}
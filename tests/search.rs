@@ -0,0 +1,246 @@
+use fixstr::{FixStr, Needle};
+
+#[test]
+fn test_find_char() {
+    let s: FixStr<32> = FixStr::new("cfg.audio.volume").unwrap();
+    assert_eq!(s.find('.'), Some(3));
+    assert_eq!(s.rfind('.'), Some(9));
+    assert_eq!(s.find('/'), None);
+
+    let s: FixStr<32> = FixStr::new("naïve café").unwrap();
+    assert_eq!(s.find('é'), Some(10));
+    assert_eq!(s.rfind('ï'), Some(2));
+}
+
+#[test]
+fn test_find_str() {
+    let s: FixStr<32> = FixStr::new("/api/v1/api").unwrap();
+    assert_eq!(s.find("api"), Some(1));
+    assert_eq!(s.rfind("api"), Some(8));
+    assert_eq!(s.find("/"), Some(0));
+    assert_eq!(s.rfind("/"), Some(7));
+    assert_eq!(s.find("v2"), None);
+    assert_eq!(s.find(""), Some(0));
+    assert_eq!(s.rfind(""), Some(11));
+}
+
+#[test]
+fn test_contains() {
+    let s: FixStr<16> = FixStr::new("user:42").unwrap();
+    assert!(s.contains(':'));
+    assert!(s.contains("er:4"));
+    assert!(!s.contains('/'));
+    assert!(!s.contains("43"));
+}
+
+#[test]
+fn test_find_byte() {
+    let s: FixStr<16> = FixStr::new("a/b/c").unwrap();
+    assert_eq!(s.find_byte(b'/'), Some(1));
+    assert_eq!(s.rfind_byte(b'/'), Some(3));
+    assert_eq!(s.find_byte(b':'), None);
+
+    let s: FixStr<16> = FixStr::new("name\0pad").unwrap();
+    assert_eq!(s.find_byte(0), Some(4));
+}
+
+#[test]
+fn test_split_once_byte() {
+    let s: FixStr<16> = FixStr::new("host:8080:x").unwrap();
+    assert_eq!(s.split_once_byte(b':'), Some(("host", "8080:x")));
+    assert_eq!(s.split_once_byte(b'/'), None);
+
+    let s: FixStr<16> = FixStr::new("é:e").unwrap();
+    assert_eq!(s.split_once_byte(0xC3), None);
+    assert_eq!(s.split_once_byte(b':'), Some(("é", "e")));
+}
+
+#[test]
+fn test_starts_and_ends_with() {
+    let s: FixStr<32> = FixStr::new("cfg.audio.volume").unwrap();
+    assert!(s.starts_with("cfg."));
+    assert!(s.starts_with('c'));
+    assert!(!s.starts_with("audio"));
+    assert!(s.ends_with(".volume"));
+    assert!(s.ends_with('e'));
+    assert!(!s.ends_with('.'));
+}
+
+#[test]
+fn test_strip_prefix_and_suffix() {
+    let s: FixStr<32> = FixStr::new("cfg.audio.volume").unwrap();
+    let rest = s.strip_prefix("cfg.").unwrap();
+    assert_eq!(rest.as_str(), "audio.volume");
+    assert_eq!(rest.strip_suffix(".volume").unwrap().as_str(), "audio");
+    assert!(s.strip_prefix("audio").is_none());
+    assert!(s.strip_suffix('x').is_none());
+
+    let s: FixStr<8> = FixStr::new("«x»").unwrap();
+    assert_eq!(s.strip_prefix('«').unwrap().as_str(), "x»");
+    assert_eq!(s.strip_suffix('»').unwrap().as_str(), "«x");
+}
+
+#[test]
+fn test_fixstr_needle() {
+    let s: FixStr<32> = FixStr::new("cfg.audio.volume").unwrap();
+    let section: FixStr<8> = FixStr::new("audio").unwrap();
+    let dot: FixStr<4> = FixStr::new(".").unwrap();
+    assert_eq!(s.find(&section), Some(4));
+    assert_eq!(s.rfind(&dot), Some(9));
+    assert!(s.contains(&section));
+    assert_eq!(s.split_fix(&dot).count(), 3);
+    assert_eq!(
+        s.strip_prefix(&FixStr::<4>::new("cfg.").unwrap())
+            .unwrap()
+            .as_str(),
+        "audio.volume"
+    );
+
+    assert_eq!((&section).find_in("sfx audio"), Some(4));
+    assert_eq!((&dot).rfind_in("a.b.c"), Some(3));
+}
+
+fn split<P: Needle>(s: &str, needle: P) -> Vec<String> {
+    let s: FixStr<32> = FixStr::new(s).unwrap();
+    s.split_fix(needle).map(String::from).collect()
+}
+
+#[test]
+fn test_split_fix() {
+    assert_eq!(split("x:y:z", ':'), ["x", "y", "z"]);
+    assert_eq!(split("a--b----c", "--"), ["a", "b", "", "c"]);
+    assert_eq!(split(":a:", ':'), ["", "a", ""]);
+    assert_eq!(split("", ':'), [""]);
+    assert_eq!(split("näive→café", '→'), ["näive", "café"]);
+}
+
+#[test]
+fn test_split_fix_matches_str_split_for_empty_needle() {
+    for s in ["", "ab", "é€"] {
+        let expected: Vec<&str> = s.split("").collect();
+        assert_eq!(split(s, ""), expected);
+    }
+}
+
+#[test]
+fn test_split_fix_into_array() {
+    let mut parts = [FixStr::<16>::default(); 3];
+    {
+        let version = String::from("1.4.12");
+        let s: FixStr<16> = FixStr::new(&version).unwrap();
+        for (slot, part) in parts.iter_mut().zip(s.split_fix('.')) {
+            *slot = part;
+        }
+    }
+    assert_eq!(parts.map(String::from), ["1", "4", "12"]);
+}
+
+#[test]
+fn test_split_array() {
+    let s: FixStr<16> = FixStr::new("10:-4:7").unwrap();
+    let [x, y, z] = s.split_array(':').unwrap();
+    assert_eq!((x, y, z), ("10", "-4", "7"));
+
+    assert_eq!(s.split_array::<2, _>(':'), None);
+    assert_eq!(s.split_array::<4, _>(':'), None);
+    assert_eq!(s.split_array::<1, _>("/"), Some(["10:-4:7"]));
+}
+
+#[test]
+fn test_split_whitespace() {
+    let s: FixStr<32> = FixStr::new("  give  player\tsword ").unwrap();
+    assert_eq!(
+        s.split_whitespace().collect::<Vec<_>>(),
+        ["give", "player", "sword"]
+    );
+
+    let words: Vec<FixStr<32>> = s.split_whitespace_fix().collect();
+    assert_eq!(words[2].as_str(), "sword");
+    assert_eq!(
+        s.split_whitespace_fix().next_back(),
+        FixStr::<32>::new("sword")
+    );
+}
+
+#[test]
+fn test_lines() {
+    let s: FixStr<32> = FixStr::new("first\r\nsecond\n\nlast").unwrap();
+    assert_eq!(
+        s.lines().collect::<Vec<_>>(),
+        ["first", "second", "", "last"]
+    );
+
+    let lines: Vec<FixStr<32>> = s.lines_fix().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[1].as_str(), "second");
+    assert_eq!(s.lines_fix().next_back(), FixStr::<32>::new("last"));
+
+    for text in [
+        "",
+        "\n",
+        "a\n",
+        "a\r",
+        "a\r\n\r\n",
+        "\nb\r\nc\n\n",
+        "x\ry\nz",
+    ] {
+        let s: FixStr<32> = FixStr::new(text).unwrap();
+        let expected: Vec<&str> = text.lines().collect();
+        let forward: Vec<FixStr<32>> = s.lines_fix().collect();
+        let mut backward: Vec<FixStr<32>> = s.lines_fix().rev().collect();
+        let forward: Vec<&str> = forward.iter().map(FixStr::as_str).collect();
+        backward.reverse();
+        let backward: Vec<&str> = backward.iter().map(FixStr::as_str).collect();
+        assert_eq!(forward, expected, "{text:?}");
+        assert_eq!(backward, expected, "{text:?}");
+    }
+}
+
+#[test]
+fn test_matches_and_indices() {
+    let s: FixStr<32> = FixStr::new("a1b22c333").unwrap();
+    assert_eq!(s.matches("22").collect::<Vec<_>>(), ["22"]);
+    assert_eq!(
+        s.match_indices('3').collect::<Vec<_>>(),
+        [(6, "3"), (7, "3"), (8, "3")]
+    );
+    assert_eq!(s.match_indices("33").collect::<Vec<_>>(), [(6, "33")]);
+}
+
+#[test]
+fn test_count_matches() {
+    let s: FixStr<32> = FixStr::new("host:port").unwrap();
+    assert_eq!(s.count_matches(':'), 1);
+    assert_eq!(s.count_matches('/'), 0);
+
+    let s: FixStr<32> = FixStr::new("aaaa").unwrap();
+    assert_eq!(s.count_matches("aa"), 2);
+
+    for text in ["", "ab", "é€"] {
+        let s: FixStr<32> = FixStr::new(text).unwrap();
+        assert_eq!(
+            s.match_indices("").collect::<Vec<_>>(),
+            text.match_indices("").collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_matches_glob() {
+    let s: FixStr<32> = FixStr::new("textures/hero_01.png").unwrap();
+    assert!(s.matches_glob("textures/*.png"));
+    assert!(s.matches_glob("*hero_??.png"));
+    assert!(s.matches_glob("*"));
+    assert!(s.matches_glob("t*s/*_*.p*g"));
+    assert!(!s.matches_glob("*.jpg"));
+    assert!(!s.matches_glob("hero*"));
+    assert!(!s.matches_glob("*hero_?.png"));
+
+    let empty = FixStr::<8>::default();
+    assert!(empty.matches_glob(""));
+    assert!(empty.matches_glob("**"));
+    assert!(!empty.matches_glob("?"));
+
+    let s: FixStr<8> = FixStr::new("café").unwrap();
+    assert!(s.matches_glob("caf?"));
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "serde")]
+
+use fixstr::{
+    AlignedFixStr, CountryCode, FixStr, FixStrList, IdentFixStr, LangTag, NonEmptyFixStr,
+    VersionFixStr,
+};
+
+#[test]
+fn test_fixstr_roundtrip() {
+    let s: FixStr<8> = FixStr::new("a\"b").unwrap();
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, r#""a\"b""#);
+    assert_eq!(serde_json::from_str::<FixStr<8>>(&json).unwrap(), s);
+
+    assert!(serde_json::from_str::<FixStr<2>>(&json).is_err());
+    assert!(serde_json::from_str::<FixStr<8>>("42").is_err());
+}
+
+#[test]
+fn test_aligned_roundtrip() {
+    let key: AlignedFixStr<8> = AlignedFixStr::new("player").unwrap();
+    let json = serde_json::to_string(&key).unwrap();
+    assert_eq!(json, r#""player""#);
+    assert_eq!(
+        serde_json::from_str::<AlignedFixStr<8>>(&json).unwrap(),
+        key
+    );
+    assert!(serde_json::from_str::<AlignedFixStr<4>>(&json).is_err());
+}
+
+#[test]
+fn test_non_empty_roundtrip() {
+    let team: NonEmptyFixStr<8> = NonEmptyFixStr::new("Red").unwrap();
+    let json = serde_json::to_string(&team).unwrap();
+    assert_eq!(
+        serde_json::from_str::<NonEmptyFixStr<8>>(&json).unwrap(),
+        team
+    );
+
+    let err = serde_json::from_str::<NonEmptyFixStr<8>>(r#""""#).unwrap_err();
+    assert!(err.to_string().contains("String is empty"));
+}
+
+fixstr::define_fixstr!(Tag, 8, |s| !s.chars().any(char::is_uppercase));
+
+#[test]
+fn test_define_fixstr_roundtrip() {
+    let tag = Tag::new("rpg").unwrap();
+    let json = serde_json::to_string(&tag).unwrap();
+    assert_eq!(json, r#""rpg""#);
+    assert_eq!(serde_json::from_str::<Tag>(&json).unwrap(), tag);
+
+    let err = serde_json::from_str::<Tag>(r#""RPG""#).unwrap_err();
+    assert!(err.to_string().contains("String is not a valid Tag"));
+}
+
+#[test]
+fn test_locale_roundtrip() {
+    let country = CountryCode::new("SE").unwrap();
+    let json = serde_json::to_string(&country).unwrap();
+    assert_eq!(json, r#""SE""#);
+    assert_eq!(serde_json::from_str::<CountryCode>(&json).unwrap(), country);
+    assert!(serde_json::from_str::<CountryCode>(r#""se""#).is_err());
+
+    let tag: LangTag<8> = LangTag::new("pt-BR").unwrap();
+    let json = serde_json::to_string(&tag).unwrap();
+    assert_eq!(serde_json::from_str::<LangTag<8>>(&json).unwrap(), tag);
+    let err = serde_json::from_str::<LangTag<8>>(r#""pt_BR""#).unwrap_err();
+    assert!(err.to_string().contains("String is not a valid LangTag"));
+}
+
+#[test]
+fn test_ident_roundtrip() {
+    let ident: IdentFixStr<8> = IdentFixStr::new("speed").unwrap();
+    let json = serde_json::to_string(&ident).unwrap();
+    assert_eq!(
+        serde_json::from_str::<IdentFixStr<8>>(&json).unwrap(),
+        ident
+    );
+    assert!(serde_json::from_str::<IdentFixStr<8>>(r#""2fast""#).is_err());
+}
+
+#[test]
+fn test_version_roundtrip() {
+    let version: VersionFixStr<8> = VersionFixStr::new("1.4.12").unwrap();
+    let json = serde_json::to_string(&version).unwrap();
+    assert_eq!(json, r#""1.4.12""#);
+    assert_eq!(
+        serde_json::from_str::<VersionFixStr<8>>(&json).unwrap(),
+        version
+    );
+    assert!(serde_json::from_str::<VersionFixStr<8>>(r#""1.04""#).is_err());
+}
+
+#[test]
+fn test_list_roundtrip() {
+    let mut list: FixStrList<8, 3> = FixStrList::new();
+    list.push(FixStr::new("a").unwrap());
+    list.push(FixStr::new("b").unwrap());
+    let json = serde_json::to_string(&list).unwrap();
+    assert_eq!(json, r#"["a","b"]"#);
+    assert_eq!(
+        serde_json::from_str::<FixStrList<8, 3>>(&json).unwrap(),
+        list
+    );
+
+    assert!(serde_json::from_str::<FixStrList<8, 3>>(r#"["a","b","c","d"]"#).is_err());
+    assert!(serde_json::from_str::<FixStrList<8, 3>>(r#"["too long value"]"#).is_err());
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "serde_json")]
+
+use fixstr::FixStr;
+use serde_json::{json, Value};
+
+#[test]
+fn test_into_value() {
+    let s: FixStr<8> = FixStr::new("alice").unwrap();
+    assert_eq!(Value::from(s), json!("alice"));
+}
+
+#[test]
+fn test_from_value() {
+    let user = json!({ "name": "alice", "age": 42 });
+    let name: FixStr<8> = (&user["name"]).try_into().unwrap();
+    assert_eq!(name.as_str(), "alice");
+
+    assert!(FixStr::<4>::try_from(&user["name"]).is_err());
+    assert_eq!(
+        FixStr::<8>::try_from(&user["age"]).unwrap_err(),
+        "expected a JSON string, found a number"
+    );
+    assert!(FixStr::<8>::try_from(user["missing"].clone()).is_err());
+}
@@ -0,0 +1,50 @@
+use fixstr::{FixStr, FixStrSet};
+
+fn tag(s: &str) -> FixStr<8> {
+    FixStr::new(s).unwrap()
+}
+
+#[test]
+fn test_insert_contains_remove() {
+    let mut tags: FixStrSet<8, 3> = FixStrSet::new();
+    assert_eq!(tags.insert(tag("enemy")), Ok(true));
+    assert_eq!(tags.insert(tag("flying")), Ok(true));
+    assert_eq!(tags.insert(tag("enemy")), Ok(false));
+    assert_eq!(tags.len(), 2);
+    assert!(tags.contains("flying"));
+    assert!(!tags.contains("boss"));
+
+    assert!(tags.remove("enemy"));
+    assert!(!tags.remove("enemy"));
+    assert_eq!(tags.as_slice(), [tag("flying")]);
+    assert!(tags.remove("flying"));
+    assert!(tags.is_empty());
+}
+
+#[test]
+fn test_full() {
+    let mut tags: FixStrSet<8, 2> = FixStrSet::new();
+    tags.insert(tag("a")).unwrap();
+    tags.insert(tag("b")).unwrap();
+    assert_eq!(tags.insert(tag("c")), Err(tag("c")));
+    assert_eq!(tags.insert(tag("a")), Ok(false));
+    assert_eq!(tags.capacity(), 2);
+
+    tags.clear();
+    assert!(tags.is_empty());
+}
+
+#[test]
+fn test_copy_eq_and_debug() {
+    let mut a: FixStrSet<8, 4> = FixStrSet::default();
+    a.insert(tag("x")).unwrap();
+    a.insert(tag("y")).unwrap();
+    let copy = a;
+
+    let mut b: FixStrSet<8, 4> = FixStrSet::new();
+    b.insert(tag("y")).unwrap();
+    b.insert(tag("x")).unwrap();
+    assert_eq!(copy, b);
+    assert_eq!(format!("{a:?}"), r#"{"x", "y"}"#);
+    assert_eq!((&a).into_iter().count(), 2);
+}
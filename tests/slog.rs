@@ -0,0 +1,52 @@
+#![cfg(feature = "slog")]
+
+use fixstr::FixStr;
+use slog::{o, Drain, Key, Logger, OwnedKVList, Record, Serializer, KV};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct StrFields(Vec<(String, String)>);
+
+impl Serializer for StrFields {
+    fn emit_arguments(&mut self, _key: Key, _val: &fmt::Arguments<'_>) -> slog::Result {
+        panic!("expected a str field");
+    }
+
+    fn emit_str(&mut self, key: Key, val: &str) -> slog::Result {
+        self.0.push((key.to_string(), val.to_string()));
+        Ok(())
+    }
+}
+
+struct Capture(Arc<Mutex<StrFields>>);
+
+impl Drain for Capture {
+    type Ok = ();
+    type Err = slog::Never;
+
+    fn log(&self, record: &Record<'_>, values: &OwnedKVList) -> Result<(), Self::Err> {
+        let mut fields = self.0.lock().unwrap();
+        record.kv().serialize(record, &mut *fields).unwrap();
+        values.serialize(record, &mut *fields).unwrap();
+        Ok(())
+    }
+}
+
+#[test]
+fn test_logs_as_str() {
+    let fields = Arc::new(Mutex::new(StrFields::default()));
+    let region: FixStr<8> = FixStr::new("eu-west").unwrap();
+    let player: FixStr<8> = FixStr::new("alice").unwrap();
+
+    let logger = Logger::root(Capture(fields.clone()), o!("region" => region));
+    slog::info!(logger, "joined"; "player" => player);
+
+    assert_eq!(
+        fields.lock().unwrap().0,
+        [
+            ("player".to_string(), "alice".to_string()),
+            ("region".to_string(), "eu-west".to_string())
+        ]
+    );
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "smartstring")]
+
+use fixstr::FixStr;
+use smartstring::alias::String as SmartString;
+
+#[test]
+fn test_into_smartstring() {
+    let s: FixStr<8> = FixStr::new("hello").unwrap();
+    let smart: SmartString = s.into();
+    assert_eq!(smart, "hello");
+}
+
+#[test]
+fn test_from_smartstring() {
+    let s: FixStr<8> = SmartString::from("hello").try_into().unwrap();
+    assert_eq!(s.as_str(), "hello");
+
+    let too_long: Result<FixStr<4>, _> = FixStr::try_from(&SmartString::from("hello"));
+    assert!(too_long.is_err());
+}
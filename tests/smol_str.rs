@@ -0,0 +1,20 @@
+#![cfg(feature = "smol_str")]
+
+use fixstr::FixStr;
+use smol_str::SmolStr;
+
+#[test]
+fn test_into_smol_str() {
+    let s: FixStr<8> = FixStr::new("hello").unwrap();
+    let smol: SmolStr = s.into();
+    assert_eq!(smol, "hello");
+}
+
+#[test]
+fn test_from_smol_str() {
+    let s: FixStr<8> = SmolStr::new("hello").try_into().unwrap();
+    assert_eq!(s.as_str(), "hello");
+
+    let too_long: Result<FixStr<4>, _> = FixStr::try_from(&SmolStr::new("hello"));
+    assert!(too_long.is_err());
+}
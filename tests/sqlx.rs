@@ -0,0 +1,40 @@
+#![cfg(feature = "sqlx")]
+
+use fixstr::FixStr;
+use sqlx::any::{AnyArguments, AnyValue};
+use sqlx::{Any, Arguments, Decode, Type, Value};
+
+fn encode<'q>(value: impl sqlx::Encode<'q, Any> + Type<Any>) -> AnyValue {
+    let mut args = AnyArguments::default();
+    args.add(value).unwrap();
+    AnyValue {
+        kind: args.values.0[0].clone(),
+    }
+}
+
+#[test]
+fn test_type_matches_str() {
+    assert_eq!(
+        <FixStr<8> as Type<Any>>::type_info(),
+        <str as Type<Any>>::type_info()
+    );
+}
+
+#[test]
+fn test_encode_as_text() {
+    let s: FixStr<8> = FixStr::new("alice").unwrap();
+    let value = encode(s);
+    assert_eq!(
+        <String as Decode<Any>>::decode(value.as_ref()).unwrap(),
+        "alice"
+    );
+}
+
+#[test]
+fn test_decode_checks_capacity() {
+    let value = encode("alice");
+    let s = <FixStr<8> as Decode<Any>>::decode(value.as_ref()).unwrap();
+    assert_eq!(s.as_str(), "alice");
+
+    assert!(<FixStr<4> as Decode<Any>>::decode(value.as_ref()).is_err());
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "symbols")]
+
+use std::thread;
+
+use fixstr::symbols::{Symbol, MAX_LEN};
+
+#[test]
+fn test_intern_and_resolve() {
+    let jump = Symbol::intern("jump").unwrap();
+    let run = Symbol::intern("run").unwrap();
+    assert_ne!(jump, run);
+    assert_eq!(Symbol::get("jump"), Some(jump));
+    assert_eq!(Symbol::get("never interned"), None);
+    assert_eq!(run.resolve().as_str(), "run");
+    assert_eq!(format!("{jump:?} {run}"), "Symbol(\"jump\") run");
+
+    assert!(Symbol::intern(&"x".repeat(MAX_LEN + 1)).is_err());
+}
+
+#[test]
+fn test_intern_across_threads() {
+    let symbols: Vec<Symbol> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| Symbol::try_from("shared").unwrap()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert!(symbols.iter().all(|&s| s == symbols[0]));
+}
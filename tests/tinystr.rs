@@ -0,0 +1,26 @@
+#![cfg(feature = "tinystr")]
+
+use fixstr::FixStr;
+use tinystr::{tinystr, TinyAsciiStr};
+
+#[test]
+fn test_into_tiny_ascii_str() {
+    let s: FixStr<8> = FixStr::new("en-US").unwrap();
+    let tiny: TinyAsciiStr<8> = s.try_into().unwrap();
+    assert_eq!(tiny, tinystr!(8, "en-US"));
+
+    let too_long: Result<TinyAsciiStr<2>, _> = s.try_into();
+    assert!(too_long.is_err());
+
+    let non_ascii: FixStr<8> = FixStr::new("café").unwrap();
+    assert!(TinyAsciiStr::<8>::try_from(non_ascii).is_err());
+}
+
+#[test]
+fn test_from_tiny_ascii_str() {
+    let s: FixStr<4> = tinystr!(4, "sv").try_into().unwrap();
+    assert_eq!(s.as_str(), "sv");
+
+    let too_long: Result<FixStr<2>, _> = tinystr!(8, "zh-Hant").try_into();
+    assert!(too_long.is_err());
+}
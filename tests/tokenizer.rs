@@ -0,0 +1,44 @@
+use fixstr::{FixStr, Tokenizer};
+
+fn tokens<const N: usize>(tokenizer: Tokenizer<'_, N>) -> Vec<Result<String, usize>> {
+    tokenizer
+        .map(|token| token.map(|t| t.to_string()).map_err(|err| err.len))
+        .collect()
+}
+
+#[test]
+fn test_whitespace() {
+    let tokenizer = Tokenizer::<8>::new("  /kick\tplayer_1 \n now ");
+    assert_eq!(
+        tokens(tokenizer),
+        [Ok("/kick".into()), Ok("player_1".into()), Ok("now".into())]
+    );
+    assert!(tokens(Tokenizer::<8>::new("   ")).is_empty());
+}
+
+#[test]
+fn test_overlong_token_is_reported_and_skipped() {
+    let tokenizer = Tokenizer::<4>::new("set resolution 1080");
+    assert_eq!(
+        tokens(tokenizer),
+        [Ok("set".into()), Err(10), Ok("1080".into())]
+    );
+}
+
+#[test]
+fn test_delimiters_and_remainder() {
+    let tokenizer = Tokenizer::<8>::with_delimiters("a=1;;b = 2", &['=', ';']);
+    assert_eq!(
+        tokens(tokenizer),
+        [
+            Ok("a".into()),
+            Ok("1".into()),
+            Ok("b ".into()),
+            Ok(" 2".into())
+        ]
+    );
+
+    let mut tokenizer = Tokenizer::<8>::new("say  hello  world");
+    assert_eq!(tokenizer.next(), Some(Ok(FixStr::new("say").unwrap())));
+    assert_eq!(tokenizer.remainder(), "hello  world");
+}
@@ -0,0 +1,37 @@
+use fixstr::{CapacityError, FixStr, TooLongError};
+
+#[test]
+fn test_fits() {
+    let s = FixStr::<16>::try_new_or_prefix("Player").unwrap();
+    assert_eq!(s.as_str(), "Player");
+}
+
+#[test]
+fn test_keeps_prefix() {
+    let error = FixStr::<16>::try_new_or_prefix("VeryLongPlayerName12345").unwrap_err();
+    assert_eq!(error.prefix.as_str(), "VeryLongPlayerNa");
+    assert_eq!(error.len, 23);
+    assert_eq!(
+        error.to_string(),
+        "String too long: 'VeryLongPlayerNa…' (23 > 16)"
+    );
+}
+
+#[test]
+fn test_prefix_ends_at_char_boundary() {
+    let error: TooLongError<4> = FixStr::try_new_or_prefix("abcé").unwrap_err();
+    assert_eq!(error.prefix.as_str(), "abc");
+    assert_eq!(error.len, 5);
+}
+
+#[test]
+fn test_into_capacity_error() {
+    let error = FixStr::<2>::try_new_or_prefix("abc").unwrap_err();
+    assert_eq!(
+        CapacityError::from(error),
+        CapacityError {
+            len: 3,
+            capacity: 2
+        }
+    );
+}
@@ -0,0 +1,107 @@
+use fixstr::FixStr;
+
+#[test]
+fn test_trim() {
+    let s: FixStr<16> = FixStr::new(" \t name \n").unwrap();
+    assert_eq!(s.trim().as_str(), "name");
+    assert_eq!(s.trim_start().as_str(), "name \n");
+    assert_eq!(s.trim_end().as_str(), " \t name");
+    assert_eq!(s.trim().capacity(), 16);
+}
+
+#[test]
+fn test_trim_matches() {
+    let s: FixStr<16> = FixStr::new("--=name=--").unwrap();
+    assert_eq!(s.trim_matches('-').as_str(), "=name=");
+    assert_eq!(s.trim_matches("--").as_str(), "=name=");
+    assert_eq!(s.trim_matches("").as_str(), "--=name=--");
+
+    let s: FixStr<16> = FixStr::new("ééxé").unwrap();
+    assert_eq!(s.trim_matches('é').as_str(), "x");
+}
+
+#[test]
+fn test_trim_in_place() {
+    let mut s: FixStr<16> = FixStr::new("  padded  ").unwrap();
+    s.trim_in_place();
+    assert_eq!(s.as_str(), "padded");
+    assert_eq!(s, FixStr::new("padded").unwrap());
+}
+
+#[test]
+fn test_pad() {
+    let s: FixStr<8> = FixStr::new("42").unwrap();
+    assert_eq!(s.pad_start(5, '0').unwrap().as_str(), "00042");
+    assert_eq!(s.pad_end(5, '.').unwrap().as_str(), "42...");
+    assert_eq!(s.center(5, ' ').unwrap().as_str(), " 42  ");
+    assert_eq!(s.center(6, '*').unwrap().as_str(), "**42**");
+    assert_eq!(s.pad_start(1, '0').unwrap().as_str(), "42");
+}
+
+#[test]
+fn test_pad_counts_chars() {
+    let s: FixStr<16> = FixStr::new("é").unwrap();
+    assert_eq!(s.pad_end(3, '·').unwrap().as_str(), "é··");
+}
+
+#[test]
+fn test_pad_exceeding_capacity() {
+    let s: FixStr<8> = FixStr::new("42").unwrap();
+    let err = s.pad_start(9, '0').unwrap_err();
+    assert_eq!((err.len, err.capacity), (9, 8));
+    assert!(s.pad_end(5, '→').is_err());
+}
+
+#[test]
+fn test_pad_huge_width() {
+    let s: FixStr<8> = FixStr::new("42").unwrap();
+    assert_eq!(s.pad_start(usize::MAX, 'é').unwrap_err().len, usize::MAX);
+    assert!(s.pad_end(usize::MAX / 2, '€').is_err());
+    assert!(s.center(usize::MAX, '0').is_err());
+}
+
+#[test]
+fn test_reversed() {
+    let s: FixStr<16> = FixStr::new("añb€").unwrap();
+    assert_eq!(s.reversed().as_str(), "€bña");
+    assert_eq!(s.reversed().reversed(), s);
+    assert_eq!(FixStr::<4>::default().reversed().as_str(), "");
+}
+
+#[test]
+fn test_escape_default() {
+    let s: FixStr<16> = FixStr::new("a\"b\né").unwrap();
+    assert_eq!(
+        s.escape_default::<32>().unwrap().as_str(),
+        "a\\\"b\\n\\u{e9}"
+    );
+
+    let err = s.escape_default::<8>().unwrap_err();
+    assert_eq!((err.len, err.capacity), (12, 8));
+}
+
+#[cfg(not(feature = "panic-free"))]
+#[test]
+fn test_escape_debug() {
+    let s: FixStr<16> = FixStr::new("a\"b\né").unwrap();
+    assert_eq!(s.escape_debug::<32>().unwrap().as_str(), "a\\\"b\\né");
+    assert_eq!(
+        s.escape_debug::<32>().unwrap().as_str(),
+        s.as_str().escape_debug().to_string()
+    );
+}
+
+#[test]
+fn test_strip_ansi() {
+    let s: FixStr<64> = FixStr::new("\x1b[1;31merror\x1b[0m: \x1b[4mdisk\x1b[24m full").unwrap();
+    let stripped = s.strip_ansi();
+    assert_eq!(stripped.as_str(), "error: disk full");
+    assert_eq!(stripped.char_len(), 16);
+
+    let s: FixStr<16> = FixStr::new("plain é").unwrap();
+    assert_eq!(s.strip_ansi(), s);
+    let s: FixStr<16> = FixStr::new("cut\x1b[1;3").unwrap();
+    assert_eq!(s.strip_ansi().as_str(), "cut");
+    let s: FixStr<16> = FixStr::new("\x1b[2Kdone").unwrap();
+    assert_eq!(s.strip_ansi().as_str(), "done");
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "uniffi")]
+
+use uniffi::{FfiConverter, Lift, Lower};
+
+uniffi::setup_scaffolding!();
+
+fixstr::uniffi_fixstr!(pub PlayerName, 8);
+
+#[derive(uniffi::Record)]
+pub struct Player {
+    pub name: PlayerName,
+}
+
+#[test]
+fn test_round_trip_through_ffi() {
+    let name = PlayerName::new("alice").unwrap();
+    let lowered = <PlayerName as Lower<UniFfiTag>>::lower(name);
+    let lifted = <PlayerName as Lift<UniFfiTag>>::try_lift(lowered).unwrap();
+    assert_eq!(lifted, name);
+}
+
+#[test]
+fn test_lift_rejects_overlong_string() {
+    let lowered = <String as FfiConverter<UniFfiTag>>::lower("much too long".to_string());
+    assert!(<PlayerName as Lift<UniFfiTag>>::try_lift(lowered).is_err());
+}
@@ -0,0 +1,21 @@
+use fixstr::FixStr;
+
+#[test]
+fn test_encode_utf16_into() {
+    let s: FixStr<16> = FixStr::new("a€😀").unwrap();
+    let mut buffer = [0u16; 8];
+    let len = s.encode_utf16_into(&mut buffer).unwrap();
+    assert_eq!(len, 4);
+    assert_eq!(String::from_utf16(&buffer[..len]).unwrap(), "a€😀");
+    assert_eq!(buffer[len], 0);
+    assert_eq!(s.encode_utf16().count(), 4);
+}
+
+#[test]
+fn test_encode_utf16_into_short_buffer() {
+    let s: FixStr<16> = FixStr::new("a€😀").unwrap();
+    let mut buffer = [7u16; 3];
+    let err = s.encode_utf16_into(&mut buffer).unwrap_err();
+    assert_eq!((err.len, err.capacity), (4, 3));
+    assert_eq!(buffer, [7; 3]);
+}
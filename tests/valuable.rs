@@ -0,0 +1,28 @@
+#![cfg(feature = "valuable")]
+
+use fixstr::FixStr;
+use valuable::{Valuable, Value, Visit};
+
+#[test]
+fn test_as_value_is_string() {
+    let s: FixStr<8> = FixStr::new("lobby").unwrap();
+    assert!(matches!(s.as_value(), Value::String("lobby")));
+}
+
+#[test]
+fn test_visit() {
+    struct Collect(Vec<String>);
+
+    impl Visit for Collect {
+        fn visit_value(&mut self, value: Value<'_>) {
+            if let Value::String(s) = value {
+                self.0.push(s.to_string());
+            }
+        }
+    }
+
+    let s: FixStr<8> = FixStr::new("lobby").unwrap();
+    let mut collect = Collect(Vec::new());
+    s.visit(&mut collect);
+    assert_eq!(collect.0, ["lobby"]);
+}
@@ -0,0 +1,60 @@
+use fixstr::{FixStr, ValidationError, VersionFixStr};
+
+fn version(s: &str) -> VersionFixStr<16> {
+    VersionFixStr::new(s).unwrap()
+}
+
+#[test]
+fn test_new() {
+    for valid in ["1", "0.1", "1.4.12", "10.0.0.255"] {
+        assert!(VersionFixStr::<16>::new(valid).is_some(), "{valid}");
+    }
+    for invalid in ["", "1.", ".1", "1..2", "1.04", "v1.2", "1.2-beta"] {
+        assert!(VersionFixStr::<16>::new(invalid).is_none(), "{invalid}");
+    }
+    assert_eq!(
+        version("1.4.12").components().collect::<Vec<_>>(),
+        ["1", "4", "12"]
+    );
+    assert_eq!(version("7").components().collect::<Vec<_>>(), ["7"]);
+}
+
+#[test]
+fn test_numeric_order() {
+    assert!(version("1.10") > version("1.9"));
+    assert!(version("1.4.12") > version("1.4.2"));
+    assert!(version("1.4.0") > version("1.4"));
+    assert!(version("2") > version("1.99.99"));
+    assert_eq!(
+        version("1.4").cmp(&version("1.4")),
+        std::cmp::Ordering::Equal
+    );
+
+    let mut versions = [version("1.10.0"), version("1.2.0"), version("1.9.3")];
+    versions.sort();
+    assert_eq!(
+        versions.map(|v| v.to_string()),
+        ["1.2.0", "1.9.3", "1.10.0"]
+    );
+}
+
+#[test]
+fn test_conversions() {
+    let fix: FixStr<16> = FixStr::new("3.1").unwrap();
+    assert_eq!(VersionFixStr::from_fixstr(fix).unwrap().into_inner(), fix);
+
+    let err = VersionFixStr::<16>::try_from("3.01").unwrap_err();
+    assert_eq!(
+        err,
+        ValidationError::Invalid {
+            type_name: "VersionFixStr"
+        }
+    );
+    assert!(matches!(
+        VersionFixStr::<2>::try_new("3.1"),
+        Err(ValidationError::Capacity(_))
+    ));
+
+    const V1: VersionFixStr<8> = VersionFixStr::new("1.0").unwrap();
+    assert_eq!(V1.as_str(), "1.0");
+}
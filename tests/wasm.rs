@@ -0,0 +1,29 @@
+#![cfg(all(feature = "wasm", not(feature = "no-unsafe")))]
+
+use fixstr::FixStr;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+// Calling into JS is only possible on wasm32, so natively this only checks that
+// FixStr is accepted as a field, argument and return type by the macro.
+
+#[wasm_bindgen]
+pub struct Player {
+    pub name: FixStr<16>,
+    pub clan: Option<FixStr<8>>,
+}
+
+#[wasm_bindgen]
+pub fn rename(player: &mut Player, name: FixStr<16>) -> FixStr<16> {
+    std::mem::replace(&mut player.name, name)
+}
+
+#[test]
+fn test_wasm_bindgen_items_compile() {
+    let mut player = Player {
+        name: FixStr::new("old").unwrap(),
+        clan: None,
+    };
+    let old = rename(&mut player, FixStr::new("new").unwrap());
+    assert_eq!(old.as_str(), "old");
+    assert_eq!(player.name.as_str(), "new");
+}
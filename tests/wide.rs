@@ -0,0 +1,27 @@
+use fixstr::{FixStr, WideFixStr};
+
+#[test]
+fn test_capacity_above_255() {
+    let path = "/very/long/path/".repeat(25);
+    assert_eq!(path.len(), 400);
+    assert!(FixStr::<512>::new(&path).is_none());
+
+    let wide: WideFixStr<512> = WideFixStr::new(&path).unwrap();
+    assert_eq!(wide.as_str(), path);
+    assert_eq!(wide.len(), 400);
+    assert_eq!(wide.capacity(), 512);
+
+    let err = WideFixStr::<256>::try_new(&path).unwrap_err();
+    assert_eq!((err.len, err.capacity), (400, 256));
+}
+
+#[test]
+fn test_traits() {
+    let a: WideFixStr<300> = "alpha".try_into().unwrap();
+    let b: WideFixStr<300> = WideFixStr::try_from(FixStr::<8>::new("beta").unwrap()).unwrap();
+    assert!(a < b);
+    assert_ne!(a, b);
+    assert_eq!(format!("{a:?}"), "WideFixStr(\"alpha\")");
+    assert_eq!(String::from(b), "beta");
+    assert!(WideFixStr::<300>::default().is_empty());
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "unicode-width")]
+
+use fixstr::FixStr;
+
+#[test]
+fn test_display_width() {
+    let cases = [
+        ("", 0),
+        ("hello", 5),
+        ("日本語", 6),
+        ("e\u{301}", 1),
+        ("🦀 crab", 7),
+    ];
+    for (text, width) in cases {
+        let s: FixStr<16> = FixStr::new(text).unwrap();
+        assert_eq!(s.display_width(), width, "{text:?}");
+    }
+}